@@ -0,0 +1,87 @@
+use crate::output::{csv_field, nanos, print_json, BenchRecord, Format, InputRecord};
+use crate::{input_source, load_day_input, solvers_for};
+use aoc_core::{input_hash, BenchReport, TimeBudget};
+
+/// Benchmarks `days` of `year`, or every day with a default input when
+/// empty, with each run of a part limited by `budget`; see [`solvers_for`].
+pub fn bench(
+    year: Option<u16>,
+    days: Vec<u8>,
+    iterations: u64,
+    input: Option<&str>,
+    format: Format,
+    budget: &TimeBudget,
+) -> Result<(), String> {
+    if input.is_some() && days.len() != 1 {
+        return Err("error: --input needs exactly one day".to_string());
+    }
+    let skip_missing = days.is_empty();
+
+    let mut printed_header = false;
+    for solver in solvers_for(year, days)? {
+        let (year, day) = (solver.year(), solver.day());
+        let Some(text) = load_day_input(year, day, input, skip_missing)? else {
+            continue;
+        };
+        let input_record = InputRecord { source: input_source(input).describe(year, day), hash: input_hash(&text) };
+        let report = aoc_core::bench(solver, &text, iterations as usize, budget).map_err(|err| err.render())?;
+        if !printed_header {
+            print_header(format);
+            printed_header = true;
+        }
+        print_report(&report, &input_record, format);
+    }
+    Ok(())
+}
+
+/// Prints the column headers for `format`, if it has any.
+fn print_header(format: Format) {
+    match format {
+        Format::Text => println!(
+            "{:>4}  {:>3}  {:<5}  {:>10}  {:>12}  {:>12}  {:>12}",
+            "year", "day", "phase", "iterations", "min", "median", "mean"
+        ),
+        Format::Csv => println!("year,day,phase,iterations,min_ns,median_ns,mean_ns,input,input_hash"),
+        Format::Json => {}
+    }
+}
+
+/// Prints one row per phase of `report`.
+fn print_report(report: &BenchReport, input: &InputRecord, format: Format) {
+    for (phase, stats) in &report.phases {
+        match format {
+            Format::Text => println!(
+                "{:>4}  {:>3}  {:<5}  {:>10}  {:>12}  {:>12}  {:>12}",
+                report.year,
+                report.day,
+                phase,
+                report.iterations,
+                format!("{:.2?}", stats.min),
+                format!("{:.2?}", stats.median),
+                format!("{:.2?}", stats.mean),
+            ),
+            Format::Csv => println!(
+                "{},{},{},{},{},{},{},{},{}",
+                report.year,
+                report.day,
+                phase,
+                report.iterations,
+                nanos(stats.min),
+                nanos(stats.median),
+                nanos(stats.mean),
+                csv_field(&input.source),
+                input.hash,
+            ),
+            Format::Json => print_json(&BenchRecord {
+                year: report.year,
+                day: report.day,
+                phase: phase.name(),
+                iterations: report.iterations,
+                min_ns: nanos(stats.min),
+                median_ns: nanos(stats.median),
+                mean_ns: nanos(stats.mean),
+                input: input.clone(),
+            }),
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use aoc_core::{Solver, FIRST_YEAR};
+
+/// Every implemented day, in order of year and day.
+pub static SOLVERS: &[&dyn Solver] = &[
+    &day_1::Day1,
+    &day_2::Day2,
+    &day_3::Day3,
+    &day_4::Day4,
+    &day_5::Day5,
+    &day_6::Day6,
+    &day_7::Day7,
+];
+
+/// Looks up the solver for `day` of `year`.
+pub fn solver(year: u16, day: u8) -> Option<&'static dyn Solver> {
+    SOLVERS.iter().copied().find(|solver| solver.year() == year && solver.day() == day)
+}
+
+/// The year commands work on unless given `--year`: always the first year
+/// rather than the latest one with a solver, so that scaffolding a day of a
+/// new year does not move every bare command over to it.
+pub const DEFAULT_YEAR: u16 = FIRST_YEAR;
+
+/// Name of the crate, and of its directory, that solves `day` of `year`:
+/// `day_<n>` for [`FIRST_YEAR`], whose crates predate other years, and
+/// `day_<year>_<n>` otherwise.
+pub fn crate_name(year: u16, day: u8) -> String {
+    if year == FIRST_YEAR {
+        format!("day_{}", day)
+    } else {
+        format!("day_{}_{}", year, day)
+    }
+}
@@ -0,0 +1,21 @@
+use crate::solvers_for;
+
+/// Checks each of `days` of `year` (see [`solvers_for`]) against its
+/// reference solution on `seeds` inputs of `size`, reporting the first
+/// divergence with a minimised input.
+pub fn diff(year: Option<u16>, days: Vec<u8>, seeds: u64, size: usize) -> Result<(), String> {
+    for solver in solvers_for(year, days)? {
+        let (year, day) = (solver.year(), solver.day());
+        // The references and generators are all for the same year
+        if year != aoc_gen::YEAR || aoc_diff::reference(day).is_none() || !aoc_gen::days().any(|d| d == day) {
+            eprintln!("skipping: day {} of {} has no reference solution or generator", day, year);
+            continue;
+        }
+
+        match aoc_diff::check(solver, 0..seeds, size) {
+            Ok(checked) => println!("day {}: {} inputs agree", day, checked),
+            Err(divergence) => return Err(divergence.to_string()),
+        }
+    }
+    Ok(())
+}
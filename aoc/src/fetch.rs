@@ -0,0 +1,20 @@
+use crate::client;
+use aoc_client::Fetched;
+use aoc_core::default_input_path;
+
+/// Downloads the input of each of `days` of `year` into the inputs
+/// directory, skipping days that are already there. The session token comes
+/// from `$AOC_SESSION`; `base_url` overrides `$AOC_BASE_URL`.
+pub fn fetch(year: u16, days: Vec<u8>, base_url: Option<String>) -> Result<(), String> {
+    let client = client(year, base_url);
+
+    for day in days {
+        let path = default_input_path(year, day);
+        match client.fetch_input(day, &path) {
+            Ok(Fetched::Cached) => println!("day {}: already cached at {}", day, path.display()),
+            Ok(Fetched::Downloaded(bytes)) => println!("day {}: downloaded {} bytes to {}", day, bytes, path.display()),
+            Err(err) => return Err(format!("error: day {}: {}", day, err)),
+        }
+    }
+    Ok(())
+}
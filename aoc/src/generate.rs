@@ -0,0 +1,17 @@
+use std::fs;
+use std::io::{self, Write};
+use std::path::Path;
+
+/// Writes a random input for `day` of `year` to `output`, or stdout when
+/// `None`.
+pub fn generate(year: u16, day: u8, seed: u64, size: usize, output: Option<&Path>) -> Result<(), String> {
+    let input = if year == aoc_gen::YEAR { aoc_gen::generate(day, seed, size) } else { None };
+    let input = input.ok_or_else(|| format!("error: day {} of {} has no input generator", day, year))?;
+
+    match output {
+        Some(path) => fs::write(path, input).map_err(|err| format!("error: failed to write {}: {}", path.display(), err)),
+        None => io::stdout()
+            .write_all(input.as_bytes())
+            .map_err(|err| format!("error: failed to write input: {}", err)),
+    }
+}
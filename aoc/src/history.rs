@@ -0,0 +1,91 @@
+use crate::solvers_for;
+use aoc_core::{alerts, default_history_path, threads, workspace_root, History, Part, RunRecord, TimedRun};
+use std::process::Command;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Adds the answered parts of `run` of `day` of `year` on the input with
+/// `input_hash` to the run history. Failing to record is only worth a
+/// warning.
+pub fn record(year: u16, day: u8, input_hash: &str, run: &TimedRun) {
+    let timestamp = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |since| since.as_secs());
+    let revision = git_revision();
+    let profile = if cfg!(debug_assertions) { "debug" } else { "release" };
+    // Timed-out parts have neither an answer nor a meaningful time
+    let records: Vec<RunRecord> = run
+        .results
+        .iter()
+        .filter_map(|result| {
+            Some(RunRecord {
+                timestamp,
+                revision: revision.clone(),
+                profile: profile.to_string(),
+                threads: threads(),
+                year,
+                day,
+                part: result.part,
+                elapsed: result.elapsed,
+                input_hash: input_hash.to_string(),
+                answer: result.answer.as_ref()?.to_string(),
+            })
+        })
+        .collect();
+
+    if let Err(err) = History::append(&default_history_path(), &records) {
+        eprintln!("warning: run not recorded: {}", err);
+    }
+}
+
+/// Short hash of the checked-out commit, with `-dirty` when tracked files
+/// have changed since; `unknown` outside a git checkout.
+fn git_revision() -> String {
+    let git = |args: &[&str]| {
+        let output = Command::new("git").args(args).current_dir(workspace_root()).output().ok()?;
+        output.status.success().then(|| String::from_utf8_lossy(&output.stdout).trim().to_string())
+    };
+    match git(&["rev-parse", "--short", "HEAD"]) {
+        Some(revision) if git(&["status", "--porcelain", "--untracked-files=no"]).is_some_and(|s| !s.is_empty()) => {
+            format!("{}-dirty", revision)
+        }
+        Some(revision) => revision,
+        None => "unknown".to_string(),
+    }
+}
+
+/// Shows the last `last` runs of each part of `days` of `year` (every day
+/// when empty; see [`solvers_for`]), flagging changed answers and runs more
+/// than `threshold` percent slower than before. With `check`, any alert
+/// makes the command fail.
+pub fn history(year: Option<u16>, days: Vec<u8>, last: usize, threshold: f64, check: bool) -> Result<(), String> {
+    let path = default_history_path();
+    let history = History::load(&path).map_err(|err| format!("error: {}", err))?;
+    let mut alerted = 0;
+
+    for solver in solvers_for(year, days)? {
+        let (year, day) = (solver.year(), solver.day());
+        for part in Part::ALL {
+            let runs = history.runs(year, day, part);
+            if runs.is_empty() {
+                continue;
+            }
+            println!("{} day {} part {} ({} runs)", year, day, part, runs.len());
+            for run in &runs[runs.len().saturating_sub(last)..] {
+                println!(
+                    "  {:<16} {:<7} {:>3} threads {:>12.2?}  {}",
+                    run.revision, run.profile, run.threads, run.elapsed, run.answer
+                );
+            }
+            for alert in alerts(&runs, last, threshold) {
+                println!("  ALERT: {}", alert);
+                alerted += 1;
+            }
+        }
+    }
+
+    if history.records().is_empty() {
+        println!("no runs recorded yet in {}", path.display());
+    }
+    if check && alerted > 0 {
+        return Err(format!("error: {} alert(s) in the run history", alerted));
+    }
+    Ok(())
+}
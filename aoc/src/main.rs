@@ -0,0 +1,297 @@
+mod bench;
+mod days;
+mod diff;
+mod fetch;
+mod generate;
+mod history;
+mod new;
+mod output;
+mod run;
+mod serve;
+mod submit;
+mod trace;
+mod verify;
+mod watch;
+
+use aoc_core::{
+    load_input, set_progress_mode, set_threads, BudgetRule, InputError, InputSource, Part, ProgressMode, Solver, TimeBudget,
+};
+use aoc_client::Client;
+use clap::{Parser, Subcommand};
+use output::Format;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+/// Advent of Code runner.
+#[derive(Parser)]
+#[command(name = "aoc")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// Puzzle year; 2024 when omitted, except that commands given no days
+    /// then cover every year
+    #[arg(long, global = true)]
+    year: Option<u16>,
+    /// Number of threads for solvers that work in parallel; one per CPU when
+    /// omitted
+    #[arg(long, global = true)]
+    threads: Option<usize>,
+    /// How long-running solvers report progress on stderr: quiet, bar or
+    /// log; a bar on a terminal when omitted, except while benchmarking
+    #[arg(long, global = true)]
+    progress: Option<ProgressMode>,
+    /// Give up on a part after this long, e.g. 30s; DAY=LIMIT or
+    /// DAY.PART=LIMIT limits only one day or part, and YEAR/DAY=LIMIT or
+    /// YEAR/DAY.PART=LIMIT only that day of one year. Repeatable, and the
+    /// most specific limit wins
+    #[arg(long, global = true, value_name = "LIMIT")]
+    timeout: Vec<BudgetRule>,
+    /// Write a Chrome trace of parse, part and hot-path spans to this file
+    #[arg(long, global = true, value_name = "FILE")]
+    trace: Option<PathBuf>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run the solver for a single day
+    Run {
+        /// Day to run (1-25)
+        day: u8,
+        /// Only run this part (1 or 2); both parts run when omitted
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Input file, or - to read stdin; defaults to the day's file in
+        /// inputs/<year>/
+        #[arg(short, long)]
+        input: Option<String>,
+        /// Output format
+        #[arg(short, long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+        /// Leave this run out of the run history
+        #[arg(long)]
+        no_history: bool,
+    },
+    /// Time parse, part one and part two of one or more days
+    Bench {
+        /// Days to benchmark; every day with an input in inputs/ when omitted
+        days: Vec<u8>,
+        /// Number of times each phase is run
+        #[arg(short = 'n', long, default_value_t = 10, value_parser = clap::value_parser!(u64).range(1..))]
+        iterations: u64,
+        /// Input file, or - to read stdin; only allowed with a single day
+        #[arg(short, long)]
+        input: Option<String>,
+        /// Output format
+        #[arg(short, long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+    },
+    /// Check every day's answers against the known-answers file
+    Verify {
+        /// Days to verify; every day with an input in inputs/ when omitted
+        days: Vec<u8>,
+        /// Answers file; defaults to answers.txt at the workspace root
+        #[arg(long)]
+        answers: Option<PathBuf>,
+        /// Add answers for inputs that have no entry yet
+        #[arg(long)]
+        record: bool,
+        /// Output format
+        #[arg(short, long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+    },
+    /// Rebuild and re-run a day whenever its source or input changes
+    ///
+    /// Each run's answers are compared with the previous run's and with the
+    /// known-answers file.
+    Watch {
+        /// Day to watch
+        day: u8,
+        /// Input file; defaults to the day's file in inputs/<year>/
+        #[arg(short, long)]
+        input: Option<String>,
+        /// Answers file; defaults to answers.txt at the workspace root
+        #[arg(long)]
+        answers: Option<PathBuf>,
+        /// Build with optimisations
+        #[arg(long)]
+        release: bool,
+    },
+    /// Show recent runs of each part and flag regressions
+    ///
+    /// Every `run` (unless given --no-history) and `verify` is recorded in
+    /// history.txt at the workspace root, or in $AOC_HISTORY.
+    History {
+        /// Days to show; every day when omitted
+        days: Vec<u8>,
+        /// Number of recent runs to show, and to compare the latest with
+        #[arg(short = 'n', long, default_value_t = 5)]
+        last: usize,
+        /// Flag the latest run when it is more than this many percent slower
+        #[arg(long, default_value_t = 20.0)]
+        threshold: f64,
+        /// Fail when there is any alert
+        #[arg(long)]
+        check: bool,
+    },
+    /// Serve the solvers over HTTP on localhost
+    ///
+    /// POST a puzzle input to /days/<day>/parts/<part> to get the answer and
+    /// timings back as JSON; GET /days lists the implemented days.
+    Serve {
+        /// Port to listen on; 0 picks a free one
+        #[arg(short, long, default_value_t = 3024)]
+        port: u16,
+    },
+    /// Compare solvers with naive reference solutions on generated inputs
+    Diff {
+        /// Days to check; every day with a reference solution when omitted
+        days: Vec<u8>,
+        /// Number of inputs to generate per day, from seed 0 upwards
+        #[arg(short, long, default_value_t = 100)]
+        seeds: u64,
+        /// Size of each generated input, kept small so the references stay fast
+        #[arg(short = 'n', long, default_value_t = 20)]
+        size: usize,
+    },
+    /// Download puzzle inputs into inputs/<year>/, skipping days already there
+    ///
+    /// Needs the session cookie of a logged-in user in $AOC_SESSION.
+    Fetch {
+        /// Days to download
+        #[arg(required = true, value_parser = clap::value_parser!(u8).range(1..=25))]
+        days: Vec<u8>,
+        /// Site to download from; $AOC_BASE_URL, or adventofcode.com, when omitted
+        #[arg(long)]
+        base_url: Option<String>,
+    },
+    /// Submit an answer, recording the verdict in the known-answers file
+    ///
+    /// Answers the file already knows to be wrong are refused without
+    /// asking the site. Needs the session cookie of a logged-in user in
+    /// $AOC_SESSION.
+    Submit(submit::SubmitArgs),
+    /// Create the crate for a new day, wired into the runner
+    ///
+    /// The crate gets a parser stub, part stubs and an example-test
+    /// skeleton; its input in inputs/<year>/ starts as an empty placeholder.
+    New {
+        /// Day to create
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+    /// Write a random puzzle input for a day in the format its parser accepts
+    Generate {
+        /// Day to generate an input for
+        day: u8,
+        /// Seed for the random generator; the same seed gives the same input
+        #[arg(short, long, default_value_t = 0)]
+        seed: u64,
+        /// Number of lines, or side length for grid days
+        #[arg(short = 'n', long, default_value_t = aoc_gen::DEFAULT_SIZE)]
+        size: usize,
+        /// File to write; stdout when omitted
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+}
+
+/// A client for the puzzle site's `year`, configured from the environment
+/// unless `base_url` overrides where it sends requests.
+fn client(year: u16, base_url: Option<String>) -> Client {
+    let client = Client::from_env().with_year(year);
+    match base_url {
+        Some(base_url) => client.with_base_url(base_url),
+        None => client,
+    }
+}
+
+fn input_source(input: Option<&str>) -> InputSource {
+    input.map_or(InputSource::Default, InputSource::from_arg)
+}
+
+fn solver(year: u16, day: u8) -> Result<&'static dyn Solver, String> {
+    days::solver(year, day).ok_or_else(|| format!("error: day {} of {} is not implemented", day, year))
+}
+
+/// Solvers for `days` of `year` (the default year when `None`), or when
+/// `days` is empty, for every implemented day of `year` (of every year when
+/// `None`).
+fn solvers_for(year: Option<u16>, days: Vec<u8>) -> Result<Vec<&'static dyn Solver>, String> {
+    if days.is_empty() {
+        Ok(days::SOLVERS.iter().copied().filter(|solver| year.is_none_or(|year| solver.year() == year)).collect())
+    } else {
+        let year = year.unwrap_or(days::DEFAULT_YEAR);
+        days.into_iter().map(|day| solver(year, day)).collect()
+    }
+}
+
+/// Loads the input for `day` of `year`. With `skip_missing`, a day without a
+/// default input is reported on stderr and yields `None` instead of an
+/// error.
+fn load_day_input(year: u16, day: u8, input: Option<&str>, skip_missing: bool) -> Result<Option<String>, String> {
+    match load_input(year, day, &input_source(input)) {
+        Ok(input) => Ok(Some(input)),
+        Err(err @ InputError::NotFound { .. }) if skip_missing => {
+            eprintln!("skipping: {}", err);
+            Ok(None)
+        }
+        Err(err) => Err(format!("error: {}", err)),
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    if let Some(threads) = cli.threads {
+        if let Err(message) = set_threads(threads) {
+            eprintln!("error: {}", message);
+            return ExitCode::FAILURE;
+        }
+    }
+
+    let progress = cli.progress.unwrap_or(match cli.command {
+        Command::Bench { .. } => ProgressMode::Quiet,
+        _ => ProgressMode::auto(),
+    });
+    set_progress_mode(progress);
+    let budget = TimeBudget::new(cli.timeout.clone());
+    let year = cli.year.unwrap_or(days::DEFAULT_YEAR);
+    // Held until the end of `main`, when the trace file is finished off
+    let _trace = match cli.trace.as_deref().map(trace::start).transpose() {
+        Ok(guard) => guard,
+        Err(message) => {
+            eprintln!("{}", message);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let result = match cli.command {
+        Command::Run { day, part, input, format, no_history } => {
+            run::run(year, day, part.and_then(Part::from_number), input.as_deref(), format, !no_history, &budget)
+        }
+        Command::Bench { days, iterations, input, format } => {
+            bench::bench(cli.year, days, iterations, input.as_deref(), format, &budget)
+        }
+        Command::Verify { days, answers, record, format } => {
+            verify::verify(cli.year, days, answers, record, format, &budget)
+        }
+        Command::Watch { day, input, answers, release } => {
+            let options = watch::RunOptions { threads: cli.threads, progress: cli.progress, timeout: &cli.timeout };
+            watch::watch(year, day, input.as_deref(), answers, release, &options)
+        }
+        Command::History { days, last, threshold, check } => history::history(cli.year, days, last, threshold, check),
+        Command::Serve { port } => serve::serve(port, &budget),
+        Command::Diff { days, seeds, size } => diff::diff(cli.year, days, seeds, size),
+        Command::Fetch { days, base_url } => fetch::fetch(year, days, base_url),
+        Command::Submit(args) => submit::submit(year, args, &budget),
+        Command::New { day } => new::new(year, day),
+        Command::Generate { day, seed, size, output } => generate::generate(year, day, seed, size, output.as_deref()),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("{}", message);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,289 @@
+//! `new`: scaffolding for the next day's crate.
+//!
+//! Creates the day's crate (see [`crate_name`]) with a manifest, a library
+//! holding a parser stub, part stubs and an example-test skeleton, and a
+//! binary that goes through
+//! [`aoc_core::cli`]; adds the crate to the workspace and to the runner's
+//! dependencies and [`SOLVERS`](crate::days::SOLVERS); and leaves an empty
+//! placeholder for the input, which `aoc fetch` fills in.
+
+use crate::days::crate_name;
+use aoc_core::{default_input_path, workspace_root, FIRST_YEAR};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Scaffolds `day` of `year` in this workspace and prints what was created.
+pub fn new(year: u16, day: u8) -> Result<(), String> {
+    let root = workspace_root();
+    for path in scaffold(&root, year, day, &default_input_path(year, day))? {
+        println!("created {}", path.strip_prefix(&root).unwrap_or(&path).display());
+    }
+    println!(
+        "day {} of {} is wired into the runner; `aoc fetch {} --year {}` downloads its input",
+        day, year, day, year
+    );
+    Ok(())
+}
+
+/// Creates the crate for `day` of `year` under `root` and wires it into the
+/// workspace and the runner, with an empty placeholder at `input` unless
+/// there is an input there already. Returns the files created.
+///
+/// Every edit is worked out before anything is written, so a day that is
+/// already there, or a manifest that cannot be edited, leaves the tree as
+/// it was.
+fn scaffold(root: &Path, year: u16, day: u8, input: &Path) -> Result<Vec<PathBuf>, String> {
+    let name = crate_name(year, day);
+    let dir = root.join(&name);
+    if dir.exists() {
+        return Err(format!("error: {} already exists", dir.display()));
+    }
+
+    let edits = [
+        (root.join("Cargo.toml"), format!("\"{}\",", name)),
+        (
+            root.join("aoc").join("Cargo.toml"),
+            format!("{0} = {{ path = \"../{0}\", default-features = false }}", name),
+        ),
+        (root.join("aoc").join("src").join("days.rs"), format!("&{}::Day{},", name, day)),
+    ];
+    let mut edited = Vec::new();
+    for (path, entry) in edits {
+        let text = fs::read_to_string(&path).map_err(|err| format!("error: failed to read {}: {}", path.display(), err))?;
+        let text = insert_entry(&text, (year, day), &entry)
+            .map_err(|message| format!("error: {}: {}", path.display(), message))?;
+        edited.push((path, text));
+    }
+
+    let mut files = vec![
+        (dir.join("Cargo.toml"), manifest(&name)),
+        (dir.join("src").join("lib.rs"), library(year, day)),
+        (dir.join("src").join("main.rs"), binary(&name, day)),
+    ];
+    if !input.exists() {
+        files.push((input.to_path_buf(), String::new()));
+    }
+
+    let created = files.iter().map(|(path, _)| path.clone()).collect();
+    for (path, text) in files.into_iter().chain(edited) {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|err| format!("error: failed to create {}: {}", parent.display(), err))?;
+        }
+        fs::write(&path, text).map_err(|err| format!("error: failed to write {}: {}", path.display(), err))?;
+    }
+    Ok(created)
+}
+
+/// The year and day a line of a list of day crates is about, e.g.
+/// `(2024, 7)` for `"day_7",`, `day_7 = { ... }` or `&day_7::Day7,`, and
+/// `(2025, 7)` for `"day_2025_7",`; see [`crate_name`].
+fn day_entry(line: &str) -> Option<(u16, u8)> {
+    fn number(text: &str) -> Option<(&str, &str)> {
+        let digits = text.find(|c: char| !c.is_ascii_digit()).unwrap_or(text.len());
+        (digits > 0).then(|| text.split_at(digits))
+    }
+
+    let rest = line.trim_start().trim_start_matches(['"', '&']).strip_prefix("day_")?;
+    let (first, rest) = number(rest)?;
+    match rest.strip_prefix('_').and_then(number) {
+        Some((day, _)) => Some((first.parse().ok()?, day.parse().ok()?)),
+        None => Some((FIRST_YEAR, first.parse().ok()?)),
+    }
+}
+
+/// Adds `entry` for `key`, a year and day, to the list of day entries in
+/// `text`, keeping the list in order and the indentation of its neighbours.
+fn insert_entry(text: &str, key: (u16, u8), entry: &str) -> Result<String, String> {
+    let lines: Vec<&str> = text.lines().collect();
+    let days: Vec<(usize, (u16, u8))> =
+        lines.iter().enumerate().filter_map(|(i, line)| Some((i, day_entry(line)?))).collect();
+    if days.iter().any(|&(_, existing)| existing == key) {
+        return Err(format!("day {} of {} is already listed", key.1, key.0));
+    }
+    let at = match days.iter().rev().find(|&&(_, existing)| existing < key) {
+        Some(&(i, _)) => i + 1,
+        None => days.first().ok_or("no list of days to add to")?.0,
+    };
+    let neighbour = lines[if at > 0 && day_entry(lines[at - 1]).is_some() { at - 1 } else { at }];
+    let indent = &neighbour[..neighbour.len() - neighbour.trim_start().len()];
+
+    let mut out = String::new();
+    for (i, line) in lines.iter().enumerate() {
+        if i == at {
+            out.push_str(&format!("{}{}\n", indent, entry));
+        }
+        out.push_str(line);
+        out.push('\n');
+    }
+    if at == lines.len() {
+        out.push_str(&format!("{}{}\n", indent, entry));
+    }
+    Ok(out)
+}
+
+fn manifest(name: &str) -> String {
+    format!(
+        r#"[package]
+name = "{name}"
+version = "0.1.0"
+edition = "2021"
+
+[features]
+default = ["parallel"]
+parallel = ["aoc_core/parallel"]
+
+[dependencies]
+aoc_core = {{ path = "../aoc_core" }}
+
+[dev-dependencies]
+proptest = "1"
+"#
+    )
+}
+
+fn library(year: u16, day: u8) -> String {
+    format!(
+        r#"//! Day {day}: TODO puzzle title.
+//!
+//! TODO: summarise the puzzle.
+#![warn(missing_docs)]
+
+use aoc_core::{{Answer, ParseError, Solution}};
+
+/// [`Solution`] for day {day}.
+pub struct Day{day};
+
+impl Solution for Day{day} {{
+    const YEAR: u16 = {year};
+    const DAY: u8 = {day};
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {{
+        // TODO: parse the input, reporting bad lines with `ParseError::at`
+        Ok(input.lines().map(str::to_string).collect())
+    }}
+
+    fn part_one(lines: &Self::Input) -> Answer {{
+        part_one(lines).into()
+    }}
+
+    fn part_two(lines: &Self::Input) -> Answer {{
+        part_two(lines).into()
+    }}
+}}
+
+/// TODO: the answer to part one.
+pub fn part_one(_lines: &[String]) -> usize {{
+    0
+}}
+
+/// TODO: the answer to part two.
+pub fn part_two(_lines: &[String]) -> usize {{
+    0
+}}
+
+#[cfg(test)]
+mod tests {{
+    use super::*;
+
+    // TODO: the example from the puzzle description
+    const EXAMPLE: &str = "";
+
+    #[test]
+    #[ignore = "fill in the example and its answer"]
+    fn test_example_part1() {{
+        assert_eq!(Day{day}::part_one(&Day{day}::parse(EXAMPLE).unwrap()), Answer::from(0));
+    }}
+
+    #[test]
+    #[ignore = "fill in the example and its answer"]
+    fn test_example_part2() {{
+        assert_eq!(Day{day}::part_two(&Day{day}::parse(EXAMPLE).unwrap()), Answer::from(0));
+    }}
+}}
+"#
+    )
+}
+
+fn binary(name: &str, day: u8) -> String {
+    format!(
+        r#"use std::process::ExitCode;
+
+fn main() -> ExitCode {{
+    aoc_core::cli::main(&{name}::Day{day})
+}}
+"#
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    #[test]
+    fn test_insert_entry_keeps_day_order_and_indentation() {
+        let members = "members = [\n    \"aoc\",\n    \"day_1\",\n    \"day_10\",\n]\n";
+        assert_eq!(
+            insert_entry(members, (2024, 2), "\"day_2\",").unwrap(),
+            "members = [\n    \"aoc\",\n    \"day_1\",\n    \"day_2\",\n    \"day_10\",\n]\n"
+        );
+        assert_eq!(
+            insert_entry("x = 1\nday_3 = { path = \"../day_3\" }\n", (2024, 1), "day_1 = {}").unwrap(),
+            "x = 1\nday_1 = {}\nday_3 = { path = \"../day_3\" }\n"
+        );
+        assert!(insert_entry(members, (2024, 10), "\"day_10\",").is_err());
+        assert!(insert_entry("[dependencies]\n", (2024, 1), "day_1 = {}").is_err());
+    }
+
+    #[test]
+    fn test_later_years_come_after_the_first() {
+        assert_eq!(day_entry("    &day_7::Day7,"), Some((FIRST_YEAR, 7)));
+        assert_eq!(day_entry("day_2025_12 = { path = \"../day_2025_12\" }"), Some((2025, 12)));
+        assert_eq!(day_entry("\"aoc_core\","), None);
+
+        let solvers = "&[\n    &day_7::Day7,\n    &day_2025_1::Day1,\n]\n";
+        assert_eq!(
+            insert_entry(solvers, (2024, 8), "&day_8::Day8,").unwrap(),
+            "&[\n    &day_7::Day7,\n    &day_8::Day8,\n    &day_2025_1::Day1,\n]\n"
+        );
+        assert_eq!(
+            insert_entry(solvers, (2025, 2), "&day_2025_2::Day2,").unwrap(),
+            "&[\n    &day_7::Day7,\n    &day_2025_1::Day1,\n    &day_2025_2::Day2,\n]\n"
+        );
+    }
+
+    #[test]
+    fn test_scaffold_wires_the_day_in() {
+        let root = env::temp_dir().join(format!("aoc-new-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("aoc").join("src")).unwrap();
+        fs::write(root.join("Cargo.toml"), "members = [\n    \"aoc\",\n    \"day_7\",\n]\n").unwrap();
+        fs::write(root.join("aoc").join("Cargo.toml"), "[dependencies]\nday_7 = { path = \"../day_7\" }\n").unwrap();
+        fs::write(root.join("aoc").join("src").join("days.rs"), "SOLVERS = &[\n    &day_7::Day7,\n];\n").unwrap();
+        let input = root.join("inputs").join("2024").join("day_8.txt");
+
+        let created = scaffold(&root, 2024, 8, &input).unwrap();
+        let read = |path: PathBuf| fs::read_to_string(path).unwrap();
+        assert_eq!(created.len(), 4);
+        assert_eq!(read(input.clone()), "");
+        assert!(read(root.join("day_8").join("src").join("lib.rs")).contains("const YEAR: u16 = 2024;"));
+        assert!(read(root.join("day_8").join("src").join("main.rs")).contains("&day_8::Day8"));
+        assert!(read(root.join("Cargo.toml")).contains("    \"day_7\",\n    \"day_8\",\n"));
+        let runner = read(root.join("aoc").join("Cargo.toml"));
+        assert!(runner.ends_with("day_8 = { path = \"../day_8\", default-features = false }\n"));
+        assert!(read(root.join("aoc").join("src").join("days.rs")).contains("    &day_8::Day8,\n];"));
+
+        // A second attempt changes nothing
+        let days = read(root.join("aoc").join("src").join("days.rs"));
+        assert!(scaffold(&root, 2024, 8, &input).is_err());
+        assert_eq!(read(root.join("aoc").join("src").join("days.rs")), days);
+
+        // Day 8 of another year is a crate of its own
+        scaffold(&root, 2025, 8, &root.join("inputs").join("2025").join("day_8.txt")).unwrap();
+        assert!(read(root.join("day_2025_8").join("Cargo.toml")).contains("name = \"day_2025_8\""));
+        let days = read(root.join("aoc").join("src").join("days.rs"));
+        assert!(days.contains("    &day_8::Day8,\n    &day_2025_8::Day8,\n"));
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,138 @@
+//! Output formats shared by the commands.
+//!
+//! With `--format json` every command prints one JSON object per line (JSON
+//! Lines). The record types below define that schema; rename or remove a
+//! field only together with every consumer.
+
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// How results are written to stdout.
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// Aligned, human-readable text
+    Text,
+    /// Comma-separated values with a header row
+    Csv,
+    /// One JSON object per line
+    Json,
+}
+
+/// Identifies the input a result was computed from.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InputRecord {
+    /// File path, or `<stdin>`.
+    pub source: String,
+    /// [`aoc_core::input_hash`] of the input.
+    pub hash: String,
+}
+
+/// One answer printed by `run`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AnswerRecord {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    /// `None` when the part ran out of time.
+    pub answer: Option<String>,
+    /// Time spent parsing the input, shared by both parts.
+    pub parse_ns: u64,
+    /// Time spent solving this part.
+    pub time_ns: u64,
+    pub input: InputRecord,
+}
+
+/// Timings of one phase printed by `bench`.
+#[derive(Debug, Clone, Serialize)]
+pub struct BenchRecord {
+    pub year: u16,
+    pub day: u8,
+    /// `parse`, `part1` or `part2`.
+    pub phase: &'static str,
+    pub iterations: usize,
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub mean_ns: u64,
+    pub input: InputRecord,
+}
+
+/// The outcome for one part printed by `verify`.
+#[derive(Debug, Clone, Serialize)]
+pub struct VerifyRecord {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    /// `ok`, `mismatch`, `unknown`, `recorded` or `timed_out`.
+    pub status: &'static str,
+    /// `None` when the part ran out of time.
+    pub answer: Option<String>,
+    /// The known answer, if there is one.
+    pub expected: Option<String>,
+    /// Time spent solving this part.
+    pub time_ns: u64,
+    pub input: InputRecord,
+}
+
+/// The implemented days of a year, as listed by `serve`.
+#[derive(Debug, Clone, Serialize)]
+pub struct DaysRecord {
+    pub year: u16,
+    pub days: Vec<u8>,
+}
+
+/// Why `serve` could not answer a request.
+#[derive(Debug, Clone, Serialize)]
+pub struct ErrorRecord {
+    pub error: String,
+    /// Where in the input a parse error is, if that is the problem.
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+}
+
+/// Prints `record` as a single line of JSON.
+pub fn print_json<T: Serialize>(record: &T) {
+    println!("{}", serde_json::to_string(record).expect("records always serialize"));
+}
+
+/// Quotes `field` for CSV output when it contains a separator or quote.
+pub fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+pub fn nanos(duration: Duration) -> u64 {
+    u64::try_from(duration.as_nanos()).unwrap_or(u64::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_answer_record_schema() {
+        let record = AnswerRecord {
+            year: 2024,
+            day: 5,
+            part: 1,
+            answer: Some("143".to_string()),
+            parse_ns: 10,
+            time_ns: 20,
+            input: InputRecord { source: "inputs/2024/day_5.txt".to_string(), hash: "00ff".to_string() },
+        };
+        assert_eq!(
+            serde_json::to_string(&record).unwrap(),
+            r#"{"year":2024,"day":5,"part":1,"answer":"143","parse_ns":10,"time_ns":20,"input":{"source":"inputs/2024/day_5.txt","hash":"00ff"}}"#
+        );
+    }
+
+    #[test]
+    fn test_csv_field_quotes_separators() {
+        assert_eq!(csv_field("plain"), "plain");
+        assert_eq!(csv_field("a,b"), "\"a,b\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+    }
+}
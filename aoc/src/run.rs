@@ -0,0 +1,67 @@
+use crate::output::{csv_field, nanos, print_json, AnswerRecord, Format, InputRecord};
+use crate::{history, input_source, solver};
+use aoc_core::{input_hash, load_input, solve_timed, Part, TimeBudget};
+
+/// Solves `part` of `day` of `year` (both parts when `None`) and prints the
+/// answers, adding them to the run history when `record` is set. Parts that
+/// run out of their time budget are reported and make the command fail.
+pub fn run(
+    year: u16,
+    day: u8,
+    part: Option<Part>,
+    input: Option<&str>,
+    format: Format,
+    record: bool,
+    budget: &TimeBudget,
+) -> Result<(), String> {
+    let solver = solver(year, day)?;
+    let source = input_source(input);
+    let input = load_input(year, day, &source).map_err(|err| format!("error: {}", err))?;
+    let parts = part.map_or(Part::ALL.to_vec(), |part| vec![part]);
+    let run = solve_timed(solver, &input, &parts, budget).map_err(|err| err.render())?;
+    let input_record = InputRecord { source: source.describe(year, day), hash: input_hash(&input) };
+    if record {
+        history::record(year, day, &input_record.hash, &run);
+    }
+
+    if format == Format::Csv {
+        println!("year,day,part,answer,parse_ns,time_ns,input,input_hash");
+    }
+    let mut timed_out = 0;
+    for result in run.results {
+        let answer = result.answer.as_ref().map(ToString::to_string);
+        if answer.is_none() {
+            timed_out += 1;
+        }
+        match format {
+            Format::Text => match &answer {
+                Some(answer) => println!("Day {} part {}: {}", day, result.part, answer),
+                None => println!("Day {} part {}: timed out after {:.2?}", day, result.part, result.elapsed),
+            },
+            Format::Csv => println!(
+                "{},{},{},{},{},{},{},{}",
+                year,
+                day,
+                result.part,
+                csv_field(answer.as_deref().unwrap_or("")),
+                nanos(run.parse_time),
+                nanos(result.elapsed),
+                csv_field(&input_record.source),
+                input_record.hash,
+            ),
+            Format::Json => print_json(&AnswerRecord {
+                year,
+                day,
+                part: result.part.number(),
+                answer,
+                parse_ns: nanos(run.parse_time),
+                time_ns: nanos(result.elapsed),
+                input: input_record.clone(),
+            }),
+        }
+    }
+    if timed_out > 0 {
+        return Err(format!("error: {} part(s) timed out", timed_out));
+    }
+    Ok(())
+}
@@ -0,0 +1,176 @@
+//! `serve`: a localhost HTTP API over the solvers, for tools that would
+//! otherwise shell out to the runner.
+//!
+//! - `GET /days` lists the implemented days.
+//! - `POST /days/<day>/parts/<part>` solves one part, with the raw puzzle
+//!   input as the request body, and answers with the same record `run
+//!   --format json` prints.
+//!
+//! Both are about the runner's default year, 2024; prefixed with
+//! `/years/<year>`, they are about that year instead.
+//!
+//! Errors come back as an [`ErrorRecord`] with a matching status: 404 for
+//! unknown days and parts, 422 for input that does not parse and 504 for a
+//! part that runs out of its time budget.
+
+use crate::days;
+use crate::output::{nanos, AnswerRecord, DaysRecord, ErrorRecord, InputRecord};
+use aoc_core::{input_hash, solve_timed, Part, TimeBudget};
+use serde::Serialize;
+use std::thread;
+use tiny_http::{Header, Request, Response, Server};
+
+/// Serves the API on `port` of the loopback interface until interrupted;
+/// port 0 picks a free one. Requests are handled concurrently.
+pub fn serve(port: u16, budget: &TimeBudget) -> Result<(), String> {
+    let server =
+        Server::http(("127.0.0.1", port)).map_err(|err| format!("error: cannot listen on port {}: {}", port, err))?;
+    if let Some(addr) = server.server_addr().to_ip() {
+        eprintln!("listening on http://{}", addr);
+    }
+
+    thread::scope(|scope| {
+        for request in server.incoming_requests() {
+            scope.spawn(|| respond(request, budget));
+        }
+    });
+    Ok(())
+}
+
+fn respond(mut request: Request, budget: &TimeBudget) {
+    let mut body = String::new();
+    let (status, json) = match request.as_reader().read_to_string(&mut body) {
+        Ok(_) => handle(request.method().as_str(), request.url(), &body, budget),
+        Err(_) => error(400, "the request body must be UTF-8 text"),
+    };
+    let content_type = Header::from_bytes("Content-Type", "application/json").expect("valid header");
+    let response = Response::from_string(json).with_status_code(status).with_header(content_type);
+    // The client may have gone away; nothing to do about it
+    let _ = request.respond(response);
+}
+
+/// Routes one request and returns the status and JSON body to answer with.
+fn handle(method: &str, url: &str, body: &str, budget: &TimeBudget) -> (u16, String) {
+    let path = url.split('?').next().unwrap_or_default();
+    let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+    let (year, segments) = match &segments[..] {
+        ["years", year, rest @ ..] => match year.parse() {
+            Ok(year) => (year, rest),
+            Err(_) => return error(404, &format!("there is no year {}", year)),
+        },
+        all => (days::DEFAULT_YEAR, all),
+    };
+    match (method, segments) {
+        ("GET", ["days"]) => {
+            let days = days::SOLVERS.iter().filter(|solver| solver.year() == year).map(|solver| solver.day()).collect();
+            (200, json(&DaysRecord { year, days }))
+        }
+        ("POST", ["days", day, "parts", part]) => solve(year, day, part, body, budget),
+        (_, ["days"] | ["days", _, "parts", _]) => error(405, &format!("{} is not allowed on {}", method, path)),
+        _ => error(404, &format!("no such endpoint: {}", path)),
+    }
+}
+
+fn solve(year: u16, day: &str, part: &str, input: &str, budget: &TimeBudget) -> (u16, String) {
+    let Some(solver) = day.parse().ok().and_then(|day| days::solver(year, day)) else {
+        return error(404, &format!("day {} of {} is not implemented", day, year));
+    };
+    let Some(part) = part.parse().ok().and_then(Part::from_number) else {
+        return error(404, &format!("there is no part {}; expected 1 or 2", part));
+    };
+
+    let run = match solve_timed(solver, input, &[part], budget) {
+        Ok(run) => run,
+        Err(err) => {
+            let record = ErrorRecord { error: err.message, line: Some(err.line), column: Some(err.column) };
+            return (422, json(&record));
+        }
+    };
+    let result = &run.results[0];
+    if result.answer.is_none() {
+        return error(504, &format!("timed out after {:.2?}", result.elapsed));
+    }
+
+    let record = AnswerRecord {
+        year,
+        day: solver.day(),
+        part: part.number(),
+        answer: result.answer.as_ref().map(ToString::to_string),
+        parse_ns: nanos(run.parse_time),
+        time_ns: nanos(result.elapsed),
+        input: InputRecord { source: "<request>".to_string(), hash: input_hash(input) },
+    };
+    (200, json(&record))
+}
+
+fn error(status: u16, message: &str) -> (u16, String) {
+    (status, json(&ErrorRecord { error: message.to_string(), line: None, column: None }))
+}
+
+fn json<T: Serialize>(record: &T) -> String {
+    serde_json::to_string(record).expect("records always serialize")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::Value;
+
+    const DAY_1: &str = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n";
+
+    fn call(method: &str, url: &str, body: &str) -> (u16, Value) {
+        let (status, json) = handle(method, url, body, &TimeBudget::default());
+        (status, serde_json::from_str(&json).unwrap())
+    }
+
+    #[test]
+    fn test_solves_a_part() {
+        let (status, json) = call("POST", "/days/1/parts/2", DAY_1);
+        assert_eq!(status, 200);
+        assert_eq!((json["day"].as_u64(), json["part"].as_u64()), (Some(1), Some(2)));
+        assert_eq!(json["answer"], "31");
+        assert_eq!(json["input"]["hash"], input_hash(DAY_1));
+    }
+
+    #[test]
+    fn test_lists_days() {
+        let (status, json) = call("GET", "/days", "");
+        assert_eq!(status, 200);
+        assert_eq!(json["year"].as_u64(), Some(days::DEFAULT_YEAR.into()));
+        let default = days::SOLVERS.iter().filter(|solver| solver.year() == days::DEFAULT_YEAR).count();
+        assert_eq!(json["days"].as_array().unwrap().len(), default);
+    }
+
+    #[test]
+    fn test_routes_take_a_year() {
+        let (status, json) = call("POST", "/years/2024/days/1/parts/2", DAY_1);
+        assert_eq!(status, 200);
+        assert_eq!((json["year"].as_u64(), json["answer"].as_str()), (Some(2024), Some("31")));
+        assert_eq!(call("POST", "/years/2015/days/1/parts/2", DAY_1).0, 404);
+        assert_eq!(call("GET", "/years/2015/days", "").1["days"], serde_json::json!([]));
+        assert_eq!(call("GET", "/years/next/days", "").0, 404);
+    }
+
+    #[test]
+    fn test_parse_errors_point_at_the_input() {
+        let (status, json) = call("POST", "/days/1/parts/1", "3   4\n4   x\n");
+        assert_eq!(status, 422);
+        assert_eq!((json["line"].as_u64(), json["column"].as_u64()), (Some(2), Some(5)));
+    }
+
+    #[test]
+    fn test_unknown_routes_and_methods() {
+        assert_eq!(call("POST", "/days/26/parts/1", "").0, 404);
+        assert_eq!(call("POST", "/days/1/parts/3", "").0, 404);
+        assert_eq!(call("GET", "/days/1/parts/1", "").0, 405);
+        assert_eq!(call("GET", "/nothing", "").0, 404);
+    }
+
+    #[test]
+    fn test_timeouts_are_reported() {
+        let budget = TimeBudget::new(vec!["50ms".parse().unwrap()]);
+        let equation = format!("1:{}\n", " 0".repeat(30));
+        let (status, json) = handle("POST", "/days/7/parts/2", &equation, &budget);
+        assert_eq!(status, 504, "{}", json);
+    }
+}
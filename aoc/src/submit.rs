@@ -0,0 +1,91 @@
+use crate::{client, input_source, solver};
+use aoc_client::{Outcome, Submission};
+use aoc_core::{
+    default_answers_path, input_hash, load_input, parse_duration, solve_timed, AnswerStore, Part, TimeBudget, Verdict,
+};
+use clap::Args;
+use std::path::PathBuf;
+use std::time::Duration;
+
+#[derive(Args)]
+pub struct SubmitArgs {
+    /// Day to submit an answer for
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+    /// Part to submit an answer for (1 or 2)
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+    /// Answer to submit; the solver's answer when omitted
+    answer: Option<String>,
+    /// Input file, or - to read stdin; defaults to the day's file in
+    /// inputs/<year>/
+    #[arg(short, long)]
+    input: Option<String>,
+    /// Answers file; defaults to answers.txt at the workspace root
+    #[arg(long)]
+    answers: Option<PathBuf>,
+    /// Site to submit to; $AOC_BASE_URL, or adventofcode.com, when omitted
+    #[arg(long)]
+    base_url: Option<String>,
+    /// When asked to wait before submitting again, wait up to this long
+    /// (e.g. 90s) and retry instead of giving up
+    #[arg(long, value_parser = parse_duration, default_value = "0s")]
+    wait: Duration,
+}
+
+/// Submits an answer for one part of a puzzle of `year`, refusing answers
+/// the answers file already knows to be wrong, and records the verdict in
+/// that file.
+pub fn submit(year: u16, args: SubmitArgs, budget: &TimeBudget) -> Result<(), String> {
+    let SubmitArgs { day, part, answer, input, answers, base_url, wait } = args;
+    let part = Part::from_number(part).expect("clap only accepts parts 1 and 2");
+    let input = load_input(year, day, &input_source(input.as_deref())).map_err(|err| format!("error: {}", err))?;
+    let hash = input_hash(&input);
+
+    let answer = match answer {
+        Some(answer) => answer.trim().to_string(),
+        None => {
+            let run = solve_timed(solver(year, day)?, &input, &[part], budget).map_err(|err| err.render())?;
+            let answer = run.results[0].answer.as_ref().ok_or("error: the solver timed out; nothing to submit")?;
+            answer.to_string()
+        }
+    };
+
+    let path = answers.unwrap_or_else(default_answers_path);
+    let mut store = AnswerStore::load(&path).map_err(|err| format!("error: {}", err))?;
+    let submission = client(year, base_url)
+        .submit(&mut store, day, part, &hash, &answer, wait)
+        .map_err(|err| format!("error: {}", err))?;
+    // Only a verdict changes the store; known answers and replies that judged
+    // nothing leave it, and the file, as they were
+    if let Submission::Sent(Outcome::Judged(_)) = submission {
+        store.save(&path).map_err(|err| format!("error: {}", err))?;
+    }
+
+    let prefix = format!("{} day {} part {}: {}", year, day, part, answer);
+    match submission {
+        Submission::Known(Verdict::Correct) => {
+            println!("{} is already known to be correct; not submitting", prefix);
+            Ok(())
+        }
+        Submission::Known(verdict) => {
+            Err(format!("error: {} is already known to be {}; not submitting", prefix, verdict))
+        }
+        Submission::Sent(Outcome::Judged(Verdict::Correct)) => {
+            println!("{} is correct", prefix);
+            Ok(())
+        }
+        Submission::Sent(Outcome::Judged(verdict)) => Err(format!("error: {} is {}", prefix, verdict)),
+        Submission::Sent(Outcome::RateLimited(wait)) => Err(format!(
+            "error: {} was not judged: submitting too often; try again in {}s, or pass --wait",
+            prefix,
+            wait.as_secs()
+        )),
+        Submission::Sent(Outcome::WrongLevel) => {
+            Err(format!("error: {} was not judged: the part is already solved or still locked", prefix))
+        }
+        Submission::Sent(Outcome::Unrecognised(text)) => {
+            Err(format!("error: {} got a reply that could not be understood: {}", prefix, text))
+        }
+    }
+}
@@ -0,0 +1,43 @@
+//! `--trace`: records the solvers' tracing spans to a file in the Chrome
+//! trace event format, for chrome://tracing or https://ui.perfetto.dev.
+//!
+//! Parse and each part get a span per day; hot paths such as day 6's
+//! `would_create_loop` add finer, debug-level spans underneath.
+
+use std::fs::File;
+use std::path::Path;
+use tracing_chrome::{ChromeLayerBuilder, FlushGuard};
+use tracing_subscriber::prelude::*;
+
+/// Starts recording every span to `path`. The file is complete once the
+/// returned guard is dropped.
+pub fn start(path: &Path) -> Result<FlushGuard, String> {
+    let file = File::create(path).map_err(|err| format!("error: cannot create {}: {}", path.display(), err))?;
+    let (layer, guard) = ChromeLayerBuilder::new().writer(file).include_args(true).build();
+    tracing_subscriber::registry().with(layer).init();
+    Ok(guard)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::{solve_timed, Part, TimeBudget};
+    use std::fs;
+
+    #[test]
+    fn test_trace_records_part_and_hot_path_spans() {
+        let path = std::env::temp_dir().join(format!("aoc-trace-{}.json", std::process::id()));
+        let guard = start(&path).unwrap();
+        let solver = crate::days::solver(2024, 6).unwrap();
+        solve_timed(solver, "....#\n.....\n..^..\n.#...\n", &Part::ALL, &TimeBudget::default()).unwrap();
+        drop(guard);
+
+        let trace = fs::read_to_string(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        let events: serde_json::Value = serde_json::from_str(&trace).unwrap();
+        let names: Vec<&str> = events.as_array().unwrap().iter().filter_map(|event| event["name"].as_str()).collect();
+        for span in ["parse", "part_one", "part_two", "would_create_loop"] {
+            assert!(names.contains(&span), "no {} span in {:?}", span, names);
+        }
+    }
+}
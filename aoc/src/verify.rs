@@ -0,0 +1,142 @@
+use crate::output::{csv_field, nanos, print_json, Format, InputRecord, VerifyRecord};
+use crate::{history, input_source, load_day_input, solvers_for};
+use aoc_core::{default_answers_path, input_hash, solve_timed, AnswerStore, KnownAnswer, Part, TimeBudget};
+use std::path::PathBuf;
+
+/// How an answer compares with the answers file.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Status {
+    Ok,
+    Mismatch,
+    Unknown,
+    Recorded,
+    TimedOut,
+}
+
+impl Status {
+    fn name(self) -> &'static str {
+        match self {
+            Status::Ok => "ok",
+            Status::Mismatch => "mismatch",
+            Status::Unknown => "unknown",
+            Status::Recorded => "recorded",
+            Status::TimedOut => "timed_out",
+        }
+    }
+}
+
+/// Runs `days` of `year` (every day with a default input when empty; see
+/// [`solvers_for`]) and compares each answer with the answers file. With
+/// `record`, answers for inputs that have no entry yet are added to the
+/// file. A part that runs out of its time budget counts as a failure, like a
+/// mismatch.
+pub fn verify(
+    year: Option<u16>,
+    days: Vec<u8>,
+    answers: Option<PathBuf>,
+    record: bool,
+    format: Format,
+    budget: &TimeBudget,
+) -> Result<(), String> {
+    let path = answers.unwrap_or_else(default_answers_path);
+    let mut store = AnswerStore::load(&path).map_err(|err| format!("error: {}", err))?;
+    let skip_missing = days.is_empty();
+    let mut mismatches = 0;
+    let mut timed_out = 0;
+    let mut recorded = 0;
+
+    if format == Format::Csv {
+        println!("year,day,part,status,answer,expected,time_ns,input,input_hash");
+    }
+    for solver in solvers_for(year, days)? {
+        let (year, day) = (solver.year(), solver.day());
+        let Some(input) = load_day_input(year, day, None, skip_missing)? else {
+            continue;
+        };
+        let hash = input_hash(&input);
+        let input_record = InputRecord { source: input_source(None).describe(year, day), hash: hash.clone() };
+        let run = solve_timed(solver, &input, &Part::ALL, budget).map_err(|err| err.render())?;
+        history::record(year, day, &hash, &run);
+
+        for result in run.results {
+            let part = result.part;
+            let answer = result.answer.as_ref().map(ToString::to_string);
+            let expected = store.lookup(year, day, part, &hash).map(|known| known.expected.clone());
+            let status = match (&answer, &expected) {
+                (None, _) => {
+                    timed_out += 1;
+                    Status::TimedOut
+                }
+                (Some(answer), Some(expected)) if answer == expected => Status::Ok,
+                (Some(_), Some(_)) => {
+                    mismatches += 1;
+                    Status::Mismatch
+                }
+                (Some(answer), None) if record => {
+                    let (input_hash, expected) = (hash.clone(), answer.clone());
+                    store
+                        .record(KnownAnswer { year, day, part, input_hash, expected })
+                        .map_err(|err| format!("error: {} day {} part {}: {}", year, day, part, err))?;
+                    recorded += 1;
+                    Status::Recorded
+                }
+                (Some(_), None) => Status::Unknown,
+            };
+            let shown = answer.as_deref().unwrap_or_default();
+
+            match format {
+                Format::Text => match status {
+                    Status::Ok => println!("{} day {} part {}: ok ({})", year, day, part, shown),
+                    Status::Mismatch => println!(
+                        "{} day {} part {}: MISMATCH: expected {}, got {}",
+                        year,
+                        day,
+                        part,
+                        expected.as_deref().unwrap_or_default(),
+                        shown
+                    ),
+                    Status::Recorded => println!("{} day {} part {}: recorded {}", year, day, part, shown),
+                    Status::Unknown => {
+                        println!("{} day {} part {}: no known answer for this input (got {})", year, day, part, shown)
+                    }
+                    Status::TimedOut => {
+                        println!("{} day {} part {}: TIMED OUT after {:.2?}", year, day, part, result.elapsed)
+                    }
+                },
+                Format::Csv => println!(
+                    "{},{},{},{},{},{},{},{},{}",
+                    year,
+                    day,
+                    part,
+                    status.name(),
+                    csv_field(shown),
+                    csv_field(expected.as_deref().unwrap_or("")),
+                    nanos(result.elapsed),
+                    csv_field(&input_record.source),
+                    hash,
+                ),
+                Format::Json => print_json(&VerifyRecord {
+                    year,
+                    day,
+                    part: part.number(),
+                    status: status.name(),
+                    answer,
+                    expected,
+                    time_ns: nanos(result.elapsed),
+                    input: input_record.clone(),
+                }),
+            }
+        }
+    }
+
+    if recorded > 0 {
+        store.save(&path).map_err(|err| format!("error: {}", err))?;
+    }
+    if mismatches > 0 {
+        return Err(format!("error: {} answer(s) differ from {}", mismatches, path.display()));
+    }
+    if timed_out > 0 {
+        return Err(format!("error: {} part(s) timed out", timed_out));
+    }
+    Ok(())
+}
@@ -0,0 +1,212 @@
+use crate::days::crate_name;
+use crate::output::AnswerRecord;
+use crate::solver;
+use aoc_core::{default_answers_path, default_input_path, workspace_root, AnswerStore, BudgetRule, Part, ProgressMode};
+use std::collections::BTreeMap;
+use std::env;
+use std::ffi::OsString;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::{Duration, Instant, SystemTime};
+
+/// How often the watched files are checked for changes.
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Modification time of every file under a set of paths.
+type Snapshot = BTreeMap<PathBuf, SystemTime>;
+
+/// Runner options given to `watch` that every re-run is passed on as well.
+pub struct RunOptions<'a> {
+    pub threads: Option<usize>,
+    pub progress: Option<ProgressMode>,
+    pub timeout: &'a [BudgetRule],
+}
+
+impl RunOptions<'_> {
+    /// The options as `aoc` command-line arguments.
+    fn args(&self) -> Vec<String> {
+        let mut args = Vec::new();
+        if let Some(threads) = self.threads {
+            args.extend(["--threads".to_string(), threads.to_string()]);
+        }
+        if let Some(progress) = self.progress {
+            args.extend(["--progress".to_string(), progress.to_string()]);
+        }
+        for rule in self.timeout {
+            args.extend(["--timeout".to_string(), rule.to_string()]);
+        }
+        args
+    }
+}
+
+/// Rebuilds and re-runs `day` of `year` every time its source, the shared
+/// crates or its input change, until interrupted. Each run's answers are
+/// shown next to the previous run's and the known answers.
+pub fn watch(
+    year: u16,
+    day: u8,
+    input: Option<&str>,
+    answers: Option<PathBuf>,
+    release: bool,
+    options: &RunOptions,
+) -> Result<(), String> {
+    solver(year, day)?;
+    if input == Some("-") {
+        return Err("error: watch re-reads the input on every change, so it cannot come from stdin".to_string());
+    }
+    let input = input.map_or_else(|| default_input_path(year, day), PathBuf::from);
+    let answers = answers.unwrap_or_else(default_answers_path);
+    let root = workspace_root();
+    let watched = [
+        root.join(crate_name(year, day)),
+        root.join("aoc_core").join("src"),
+        root.join("aoc_grid").join("src"),
+        input.clone(),
+    ];
+
+    eprintln!("watching day {} and {}; press Ctrl-C to stop", day, input.display());
+    let mut seen = Snapshot::new();
+    let mut previous = BTreeMap::new();
+    loop {
+        let current = snapshot(&watched);
+        if current == seen {
+            thread::sleep(POLL_INTERVAL);
+            continue;
+        }
+        seen = current;
+
+        let started = Instant::now();
+        let Some(records) = build_and_run(year, day, &input, release, options) else {
+            eprintln!("day {}: build or run failed; waiting for changes", day);
+            continue;
+        };
+        let store = AnswerStore::load(&answers).unwrap_or_else(|err| {
+            eprintln!("warning: {}", err);
+            AnswerStore::default()
+        });
+
+        println!("--- day {} rebuilt and ran in {:.2?}", day, started.elapsed());
+        for record in records {
+            let Some(part) = Part::from_number(record.part) else {
+                continue;
+            };
+            let known = store.lookup(year, day, part, &record.input.hash).map(|known| known.expected.as_str());
+            let last = previous.insert(part, record.answer.clone());
+            println!("{}", describe(day, part, record.answer.as_deref(), last.as_ref().map(Option::as_deref), known));
+        }
+    }
+}
+
+/// Modification times of every file under `paths`; missing paths are left
+/// out, so creating one counts as a change.
+fn snapshot(paths: &[PathBuf]) -> Snapshot {
+    fn visit(path: &Path, snapshot: &mut Snapshot) {
+        let Ok(metadata) = fs::metadata(path) else {
+            return;
+        };
+        if metadata.is_dir() {
+            for entry in fs::read_dir(path).into_iter().flatten().flatten() {
+                visit(&entry.path(), snapshot);
+            }
+        } else if let Ok(modified) = metadata.modified() {
+            snapshot.insert(path.to_path_buf(), modified);
+        }
+    }
+
+    let mut snapshot = Snapshot::new();
+    for path in paths {
+        visit(path, &mut snapshot);
+    }
+    snapshot
+}
+
+/// Rebuilds the runner with cargo and runs `day` of `year` on `input` with
+/// it and `options`, so the answers come from the code as it is now. Build
+/// errors go to stderr.
+fn build_and_run(year: u16, day: u8, input: &Path, release: bool, options: &RunOptions) -> Option<Vec<AnswerRecord>> {
+    let cargo = env::var_os("CARGO").unwrap_or_else(|| OsString::from("cargo"));
+    let mut command = Command::new(cargo);
+    command.arg("run").arg("--quiet").arg("--manifest-path").arg(workspace_root().join("Cargo.toml"));
+    if release {
+        command.arg("--release");
+    }
+    command.args(["--package", "aoc", "--", "run", &day.to_string(), "--year", &year.to_string()]);
+    // A re-run on every save would swamp the run history with timings of
+    // work in progress
+    command.args(["--format", "json", "--no-history", "--input"]).arg(input);
+    command.args(options.args());
+
+    let output = command.stderr(Stdio::inherit()).output().ok()?;
+    // Parts that time out make the run fail, but their records still count
+    let records: Vec<AnswerRecord> = String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect();
+    (!records.is_empty()).then_some(records)
+}
+
+/// One line describing an answer: how it compares with the previous run
+/// (`None` on the first run) and with the known answer, if there is one.
+fn describe(day: u8, part: Part, answer: Option<&str>, previous: Option<Option<&str>>, known: Option<&str>) -> String {
+    let mut line = format!("day {} part {}: {}", day, part, answer.unwrap_or("timed out"));
+    let mut notes = Vec::new();
+    match previous {
+        Some(previous) if previous == answer => notes.push("unchanged".to_string()),
+        Some(previous) => notes.push(format!("was {}", previous.unwrap_or("timed out"))),
+        None => {}
+    }
+    match (known, answer) {
+        (Some(known), Some(answer)) if known == answer => notes.push("matches the known answer".to_string()),
+        (Some(known), _) => notes.push(format!("MISMATCH: known answer is {}", known)),
+        (None, _) => {}
+    }
+    if !notes.is_empty() {
+        line.push_str(&format!(" ({})", notes.join("; ")));
+    }
+    line
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_describe_compares_with_previous_and_known() {
+        assert_eq!(describe(4, Part::One, Some("18"), None, None), "day 4 part 1: 18");
+        assert_eq!(
+            describe(4, Part::One, Some("18"), Some(Some("18")), Some("18")),
+            "day 4 part 1: 18 (unchanged; matches the known answer)"
+        );
+        assert_eq!(
+            describe(4, Part::Two, Some("10"), Some(Some("9")), Some("9")),
+            "day 4 part 2: 10 (was 9; MISMATCH: known answer is 9)"
+        );
+        assert_eq!(describe(4, Part::Two, None, Some(Some("9")), None), "day 4 part 2: timed out (was 9)");
+    }
+
+    #[test]
+    fn test_runs_get_the_runner_options() {
+        let timeout = ["7.2=1s".parse().unwrap()];
+        let options = RunOptions { threads: Some(2), progress: Some(ProgressMode::Log), timeout: &timeout };
+        assert_eq!(options.args(), ["--threads", "2", "--progress", "log", "--timeout", "7.2=1s"]);
+        assert!(RunOptions { threads: None, progress: None, timeout: &[] }.args().is_empty());
+    }
+
+    #[test]
+    fn test_snapshot_sees_new_and_changed_files() {
+        let dir = env::temp_dir().join(format!("aoc-watch-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(dir.join("src")).unwrap();
+        fs::write(dir.join("src").join("lib.rs"), "// one").unwrap();
+
+        let before = snapshot(&[dir.clone(), dir.join("missing.txt")]);
+        assert_eq!(before.len(), 1);
+        fs::write(dir.join("missing.txt"), "now here").unwrap();
+        let after = snapshot(&[dir.clone(), dir.join("missing.txt")]);
+        fs::remove_dir_all(&dir).unwrap();
+        assert_ne!(before, after);
+        assert_eq!(after.len(), 2);
+    }
+}
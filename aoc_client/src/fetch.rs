@@ -0,0 +1,41 @@
+use crate::{Client, ClientError};
+use std::fs;
+use std::path::Path;
+
+/// What [`Client::fetch_input`] did.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fetched {
+    /// The input was already cached; nothing was downloaded.
+    Cached,
+    /// The input was downloaded and cached; holds its size in bytes.
+    Downloaded(usize),
+}
+
+impl Client {
+    /// URL of `day`'s puzzle input.
+    pub fn input_url(&self, day: u8) -> String {
+        self.url(&format!("day/{}/input", day))
+    }
+
+    /// Makes sure `day`'s input is cached at `path`, downloading it only if
+    /// the file does not exist yet or is an empty placeholder. Inputs never
+    /// change, so a cached input is never fetched again.
+    ///
+    /// The download is written to a temporary file first, so a failed
+    /// request never leaves a partial input behind.
+    pub fn fetch_input(&self, day: u8, path: &Path) -> Result<Fetched, ClientError> {
+        if fs::metadata(path).is_ok_and(|metadata| metadata.is_file() && metadata.len() > 0) {
+            return Ok(Fetched::Cached);
+        }
+
+        let input = self.get(&self.input_url(day))?;
+        let io_error = |error| ClientError::Io { path: path.to_path_buf(), error };
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(io_error)?;
+        }
+        let partial = path.with_extension("part");
+        fs::write(&partial, &input).map_err(io_error)?;
+        fs::rename(&partial, path).map_err(io_error)?;
+        Ok(Fetched::Downloaded(input.len()))
+    }
+}
@@ -0,0 +1,161 @@
+//! Talks to the Advent of Code website on behalf of a logged-in user:
+//! downloads puzzle inputs into the local cache and submits answers.
+//!
+//! Every request goes to a configurable base URL, so tests can point the
+//! client at a local stub server instead of the real site.
+
+mod fetch;
+mod submit;
+
+pub use fetch::Fetched;
+pub use submit::{parse_outcome, Outcome, Submission};
+
+use aoc_core::{AnswersError, FIRST_YEAR};
+use std::env;
+use std::fmt;
+use std::io;
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// Where requests go unless configured otherwise.
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Environment variable that overrides [`DEFAULT_BASE_URL`].
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+
+/// Environment variable holding the session cookie of a logged-in user.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+const USER_AGENT: &str = concat!("aoc-runner/", env!("CARGO_PKG_VERSION"));
+
+#[derive(Debug)]
+pub enum ClientError {
+    /// A request needs a session token but none was configured.
+    NoSession,
+    /// The server answered with an error status.
+    Status { url: String, status: u16, message: String },
+    /// The server could not be reached.
+    Transport { url: String, message: String },
+    /// A cached file could not be read or written.
+    Io { path: PathBuf, error: io::Error },
+    /// An answer could not be submitted or recorded.
+    Answers(AnswersError),
+}
+
+impl fmt::Display for ClientError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ClientError::NoSession => write!(
+                f,
+                "no session token; set {} to the value of your adventofcode.com session cookie",
+                SESSION_VAR
+            ),
+            ClientError::Status { url, status, message } => {
+                write!(f, "{} answered {}", url, status)?;
+                if !message.is_empty() {
+                    write!(f, ": {}", message)?;
+                }
+                Ok(())
+            }
+            ClientError::Transport { url, message } => write!(f, "failed to reach {}: {}", url, message),
+            ClientError::Io { path, error } => write!(f, "{}: {}", path.display(), error),
+            ClientError::Answers(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl std::error::Error for ClientError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ClientError::Io { error, .. } => Some(error),
+            ClientError::Answers(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// A connection to the Advent of Code website, or a stand-in for it, for
+/// the puzzles of one year.
+pub struct Client {
+    base_url: String,
+    year: u16,
+    session: Option<String>,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    /// A client for the site at `base_url`, acting as the user whose
+    /// session cookie is `session`. Requests that need a login fail with
+    /// [`ClientError::NoSession`] when `session` is `None`.
+    ///
+    /// Requests are about [`FIRST_YEAR`]'s puzzles until
+    /// [`with_year`](Client::with_year) picks another year.
+    pub fn new(base_url: impl Into<String>, session: Option<String>) -> Client {
+        let agent = ureq::AgentBuilder::new().user_agent(USER_AGENT).timeout(Duration::from_secs(30)).build();
+        Client { base_url: base_url.into().trim_end_matches('/').to_string(), year: FIRST_YEAR, session, agent }
+    }
+
+    /// A client configured from `$AOC_BASE_URL` and `$AOC_SESSION`.
+    pub fn from_env() -> Client {
+        let base_url = env::var(BASE_URL_VAR).unwrap_or_else(|_| DEFAULT_BASE_URL.to_string());
+        let session = env::var(SESSION_VAR).ok().map(|s| s.trim().to_string()).filter(|s| !s.is_empty());
+        Client::new(base_url, session)
+    }
+
+    /// The same client, sending its requests to `base_url` instead.
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Client {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    /// The same client, for the puzzles of `year` instead.
+    pub fn with_year(mut self, year: u16) -> Client {
+        self.year = year;
+        self
+    }
+
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    /// URL of `path` under the puzzle year, e.g. `day/5/input`.
+    fn url(&self, path: &str) -> String {
+        format!("{}/{}/{}", self.base_url, self.year, path)
+    }
+
+    /// Sends a logged-in GET request and returns the response body.
+    fn get(&self, url: &str) -> Result<String, ClientError> {
+        let response = self.agent.get(url).set("Cookie", &self.cookie()?).call();
+        read_body(url, response)
+    }
+
+    /// Sends a logged-in POST of `form` and returns the response body.
+    fn post_form(&self, url: &str, form: &[(&str, &str)]) -> Result<String, ClientError> {
+        let response = self.agent.post(url).set("Cookie", &self.cookie()?).send_form(form);
+        read_body(url, response)
+    }
+
+    fn cookie(&self) -> Result<String, ClientError> {
+        let session = self.session.as_ref().ok_or(ClientError::NoSession)?;
+        Ok(format!("session={}", session))
+    }
+}
+
+/// Turns a response into its body, or an error that says what went wrong.
+fn read_body(url: &str, response: Result<ureq::Response, ureq::Error>) -> Result<String, ClientError> {
+    let transport = |message: String| ClientError::Transport { url: url.to_string(), message };
+    match response {
+        Ok(response) => response.into_string().map_err(|err| transport(err.to_string())),
+        Err(ureq::Error::Status(status, response)) => {
+            // The site explains most refusals in the first line of the body
+            let body = response.into_string().unwrap_or_default();
+            let message = body.lines().next().unwrap_or_default().trim().to_string();
+            Err(ClientError::Status { url: url.to_string(), status, message })
+        }
+        Err(ureq::Error::Transport(err)) => Err(transport(err.to_string())),
+    }
+}
@@ -0,0 +1,183 @@
+use crate::{Client, ClientError};
+use aoc_core::{check_answer, AnswerStore, KnownAnswer, Part, RejectedAnswer, Verdict};
+use std::thread;
+use std::time::Duration;
+
+/// What the site said about a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    /// The answer was judged.
+    Judged(Verdict),
+    /// An answer was submitted too recently; try again after this long.
+    RateLimited(Duration),
+    /// The part was already solved, or is not unlocked yet.
+    WrongLevel,
+    /// A reply the parser does not know, as plain text.
+    Unrecognised(String),
+}
+
+/// The result of [`Client::submit`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Submission {
+    /// Not sent, because the answers store already knew the verdict.
+    Known(Verdict),
+    /// The answer was sent; this is the reply.
+    Sent(Outcome),
+}
+
+impl Client {
+    /// URL answers for `day` are posted to.
+    pub fn answer_url(&self, day: u8) -> String {
+        self.url(&format!("day/{}/answer", day))
+    }
+
+    /// Posts `answer` for `part` of `day` and reads the reply.
+    pub fn submit_answer(&self, day: u8, part: Part, answer: &str) -> Result<Outcome, ClientError> {
+        let level = part.number().to_string();
+        let page = self.post_form(&self.answer_url(day), &[("level", &level), ("answer", answer)])?;
+        Ok(parse_outcome(&page))
+    }
+
+    /// Submits `answer` for `part` of `day` on the input with `input_hash`,
+    /// unless `store` already knows what the site would say, and records
+    /// the verdict in `store` under the client's year. An answer the store
+    /// could not record (see [`check_answer`]) is refused without sending.
+    ///
+    /// When the site asks to wait, waits and tries again for as long as the
+    /// waits add up to no more than `max_wait`.
+    pub fn submit(
+        &self,
+        store: &mut AnswerStore,
+        day: u8,
+        part: Part,
+        input_hash: &str,
+        answer: &str,
+        max_wait: Duration,
+    ) -> Result<Submission, ClientError> {
+        let year = self.year;
+        check_answer(answer).map_err(ClientError::Answers)?;
+        if let Some(verdict) = store.judge(year, day, part, input_hash, answer) {
+            return Ok(Submission::Known(verdict));
+        }
+
+        let mut waited = Duration::ZERO;
+        let outcome = loop {
+            match self.submit_answer(day, part, answer)? {
+                Outcome::RateLimited(wait) if waited + wait <= max_wait => {
+                    thread::sleep(wait);
+                    waited += wait;
+                }
+                outcome => break outcome,
+            }
+        };
+
+        let (input_hash, answer) = (input_hash.to_string(), answer.to_string());
+        let stored = match outcome {
+            Outcome::Judged(Verdict::Correct) => {
+                store.record(KnownAnswer { year, day, part, input_hash, expected: answer })
+            }
+            Outcome::Judged(verdict) => store.reject(RejectedAnswer { year, day, part, input_hash, answer, verdict }),
+            _ => Ok(()),
+        };
+        stored.map_err(ClientError::Answers)?;
+        Ok(Submission::Sent(outcome))
+    }
+}
+
+/// Reads the outcome from the page the site returns after a submission.
+pub fn parse_outcome(page: &str) -> Outcome {
+    let text = plain_text(article(page));
+    if text.contains("That's the right answer") {
+        Outcome::Judged(Verdict::Correct)
+    } else if text.contains("You gave an answer too recently") {
+        Outcome::RateLimited(wait_time(&text).unwrap_or(Duration::from_secs(60)))
+    } else if text.contains("your answer is too high") {
+        Outcome::Judged(Verdict::TooHigh)
+    } else if text.contains("your answer is too low") {
+        Outcome::Judged(Verdict::TooLow)
+    } else if text.contains("That's not the right answer") {
+        Outcome::Judged(Verdict::Wrong)
+    } else if text.contains("You don't seem to be solving the right level") {
+        Outcome::WrongLevel
+    } else {
+        Outcome::Unrecognised(text.chars().take(200).collect())
+    }
+}
+
+/// The `<article>` holding the reply, or the whole page if there is none.
+fn article(page: &str) -> &str {
+    let Some(start) = page.find("<article") else {
+        return page;
+    };
+    let end = page[start..].find("</article>").map_or(page.len(), |end| start + end);
+    &page[start..end]
+}
+
+/// `html` with its tags dropped and whitespace collapsed.
+fn plain_text(html: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Reads "You have 1m 12s left to wait" into a duration.
+fn wait_time(text: &str) -> Option<Duration> {
+    let start = text.find("You have ")? + "You have ".len();
+    let end = start + text[start..].find(" left to wait")?;
+    let mut seconds = 0;
+    for amount in text[start..end].split_whitespace() {
+        let (number, unit) = amount.split_at(amount.find(|c: char| !c.is_ascii_digit())?);
+        let number: u64 = number.parse().ok()?;
+        seconds += match unit {
+            "h" => number * 3600,
+            "m" => number * 60,
+            "s" => number,
+            _ => return None,
+        };
+    }
+    Some(Duration::from_secs(seconds))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn page(message: &str) -> String {
+        format!("<html><main>\n<article><p>{}</p></article>\n</main></html>", message)
+    }
+
+    #[test]
+    fn test_parse_verdicts() {
+        let correct = page("That's the right answer!  You are <span>one gold star</span> closer.");
+        assert_eq!(parse_outcome(&correct), Outcome::Judged(Verdict::Correct));
+        let high = page("That's not the right answer; your answer is too high.  Please wait one minute.");
+        assert_eq!(parse_outcome(&high), Outcome::Judged(Verdict::TooHigh));
+        let low = page("That's not the right answer; your answer is too low.");
+        assert_eq!(parse_outcome(&low), Outcome::Judged(Verdict::TooLow));
+        let wrong = page("That's not the right answer.  If you're stuck, make sure you're using the full input.");
+        assert_eq!(parse_outcome(&wrong), Outcome::Judged(Verdict::Wrong));
+        let level = page("You don't seem to be solving the right level.  Did you already complete it?");
+        assert_eq!(parse_outcome(&level), Outcome::WrongLevel);
+    }
+
+    #[test]
+    fn test_parse_rate_limit() {
+        let limited = page("You gave an answer too recently; you have to wait.  You have 1m 12s left to wait.");
+        assert_eq!(parse_outcome(&limited), Outcome::RateLimited(Duration::from_secs(72)));
+        let seconds = page("You gave an answer too recently.  You have 34s left to wait.");
+        assert_eq!(parse_outcome(&seconds), Outcome::RateLimited(Duration::from_secs(34)));
+    }
+
+    #[test]
+    fn test_unknown_reply_is_kept_as_text() {
+        assert_eq!(parse_outcome("<p>Something <b>new</b></p>"), Outcome::Unrecognised("Something new".to_string()));
+    }
+}
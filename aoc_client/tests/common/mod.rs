@@ -0,0 +1,79 @@
+//! A stand-in for the Advent of Code website, serving canned responses
+//! on a local port and recording every request it gets.
+
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use tiny_http::{Header, Response, Server};
+
+/// A request as the stub saw it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Seen {
+    pub method: String,
+    pub url: String,
+    pub cookie: Option<String>,
+    pub body: String,
+}
+
+/// The stub's reply to a request, given the requests before it: a status
+/// and a body.
+pub type Responder = fn(&Seen, &[Seen]) -> (u16, String);
+
+pub struct StubServer {
+    server: Arc<Server>,
+    seen: Arc<Mutex<Vec<Seen>>>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl StubServer {
+    /// Starts serving on a free local port, answering with `respond`.
+    pub fn start(respond: Responder) -> StubServer {
+        let server = Arc::new(Server::http("127.0.0.1:0").expect("stub server starts"));
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let thread = {
+            let (server, seen) = (Arc::clone(&server), Arc::clone(&seen));
+            thread::spawn(move || {
+                for mut request in server.incoming_requests() {
+                    let mut body = String::new();
+                    let _ = request.as_reader().read_to_string(&mut body);
+                    let cookie = request
+                        .headers()
+                        .iter()
+                        .find(|header| header.field.equiv("Cookie"))
+                        .map(|header| header.value.to_string());
+                    let request_seen = Seen {
+                        method: request.method().to_string(),
+                        url: request.url().to_string(),
+                        cookie,
+                        body,
+                    };
+                    let mut seen = seen.lock().unwrap();
+                    let (status, reply) = respond(&request_seen, &seen);
+                    seen.push(request_seen);
+                    drop(seen);
+                    let content_type = Header::from_bytes("Content-Type", "text/plain").unwrap();
+                    let _ = request.respond(Response::from_string(reply).with_status_code(status).with_header(content_type));
+                }
+            })
+        };
+        StubServer { server, seen, thread: Some(thread) }
+    }
+
+    /// Base URL to point a client at.
+    pub fn url(&self) -> String {
+        format!("http://{}", self.server.server_addr().to_ip().expect("stub listens on TCP"))
+    }
+
+    /// Every request received so far, oldest first.
+    pub fn seen(&self) -> Vec<Seen> {
+        self.seen.lock().unwrap().clone()
+    }
+}
+
+impl Drop for StubServer {
+    fn drop(&mut self) {
+        self.server.unblock();
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
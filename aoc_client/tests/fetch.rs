@@ -0,0 +1,105 @@
+mod common;
+
+use aoc_client::{Client, ClientError, Fetched};
+use common::{Seen, StubServer};
+use std::fs;
+use std::path::PathBuf;
+
+const INPUT: &str = "3   4\n4   3\n2   5\n";
+
+fn serve_input(seen: &Seen, _: &[Seen]) -> (u16, String) {
+    match (seen.url.as_str(), seen.cookie.as_deref()) {
+        ("/2024/day/1/input", Some("session=secret")) => (200, INPUT.to_string()),
+        (_, Some("session=secret")) => (404, "404 Not Found".to_string()),
+        _ => (400, "Puzzle inputs differ by user.  Please log in to get your puzzle input.".to_string()),
+    }
+}
+
+/// A fresh, empty directory for one test's cache.
+fn temp_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("aoc-client-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+#[test]
+fn test_fetch_downloads_with_the_session_cookie() {
+    let server = StubServer::start(serve_input);
+    let path = temp_dir("download").join("day_1.txt");
+    let client = Client::new(server.url(), Some("secret".to_string()));
+
+    assert_eq!(client.fetch_input(1, &path).unwrap(), Fetched::Downloaded(INPUT.len()));
+    assert_eq!(fs::read_to_string(&path).unwrap(), INPUT);
+    let seen = server.seen();
+    assert_eq!(seen.len(), 1);
+    assert_eq!((seen[0].method.as_str(), seen[0].url.as_str()), ("GET", "/2024/day/1/input"));
+}
+
+#[test]
+fn test_cached_input_is_never_fetched_again() {
+    let server = StubServer::start(serve_input);
+    let path = temp_dir("cached").join("day_1.txt");
+    let client = Client::new(server.url(), Some("secret".to_string()));
+
+    client.fetch_input(1, &path).unwrap();
+    assert_eq!(client.fetch_input(1, &path).unwrap(), Fetched::Cached);
+    assert_eq!(server.seen().len(), 1);
+
+    // Not even without a session, or with the server gone
+    drop(server);
+    assert_eq!(Client::new("http://127.0.0.1:9", None).fetch_input(1, &path).unwrap(), Fetched::Cached);
+}
+
+#[test]
+fn test_empty_placeholder_is_replaced() {
+    let server = StubServer::start(serve_input);
+    let path = temp_dir("placeholder").join("day_1.txt");
+    fs::write(&path, "").unwrap();
+    let client = Client::new(server.url(), Some("secret".to_string()));
+
+    assert_eq!(client.fetch_input(1, &path).unwrap(), Fetched::Downloaded(INPUT.len()));
+    assert_eq!(fs::read_to_string(&path).unwrap(), INPUT);
+}
+
+#[test]
+fn test_refusal_is_reported_and_nothing_is_cached() {
+    let server = StubServer::start(serve_input);
+    let dir = temp_dir("refused");
+    let client = Client::new(server.url(), Some("wrong".to_string()));
+
+    match client.fetch_input(1, &dir.join("day_1.txt")) {
+        Err(ClientError::Status { status: 400, message, .. }) => assert!(message.starts_with("Puzzle inputs differ")),
+        other => panic!("expected a 400, got {:?}", other),
+    }
+    assert_eq!(fs::read_dir(&dir).unwrap().count(), 0);
+}
+
+#[test]
+fn test_missing_day_is_an_error() {
+    let server = StubServer::start(serve_input);
+    let client = Client::new(server.url(), Some("secret".to_string()));
+    let err = client.fetch_input(26, &temp_dir("missing").join("day_26.txt")).unwrap_err();
+    assert!(matches!(err, ClientError::Status { status: 404, .. }), "{:?}", err);
+}
+
+#[test]
+fn test_fetch_needs_a_session() {
+    let server = StubServer::start(serve_input);
+    let client = Client::new(server.url(), None);
+    let err = client.fetch_input(1, &temp_dir("no-session").join("day_1.txt")).unwrap_err();
+    assert!(matches!(err, ClientError::NoSession));
+    assert!(server.seen().is_empty());
+}
+
+#[test]
+fn test_base_url_may_end_in_a_slash() {
+    let client = Client::new("http://localhost:1234/", None);
+    assert_eq!(client.input_url(7), "http://localhost:1234/2024/day/7/input");
+}
+
+#[test]
+fn test_inputs_are_fetched_for_the_clients_year() {
+    let client = Client::new("http://localhost:1234", None).with_year(2015);
+    assert_eq!(client.input_url(7), "http://localhost:1234/2015/day/7/input");
+}
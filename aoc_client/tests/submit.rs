@@ -0,0 +1,131 @@
+mod common;
+
+use aoc_client::{Client, ClientError, Outcome, Submission};
+use aoc_core::{AnswerStore, Part, Verdict};
+use common::{Seen, StubServer};
+use std::time::Duration;
+
+fn reply(message: &str) -> (u16, String) {
+    (200, format!("<html><body><main>\n<article><p>{}</p></article>\n</main></body></html>", message))
+}
+
+/// The answer to day 5 part 1 is 143; part 2 is already solved.
+fn judge(seen: &Seen, _: &[Seen]) -> (u16, String) {
+    match seen.body.as_str() {
+        "level=1&answer=143" => reply("That's the right answer!  You are <span>one gold star</span> closer."),
+        "level=2&answer=143" => reply("You don't seem to be solving the right level.  Did you already complete it?"),
+        body if body.starts_with("level=1&answer=1") => {
+            reply("That's not the right answer; your answer is too low.  Please wait one minute before trying again.")
+        }
+        _ => reply("That's not the right answer; your answer is too high.  Please wait one minute before trying again."),
+    }
+}
+
+/// Asks the first caller to wait a second, then accepts anything.
+fn rate_limited(_: &Seen, before: &[Seen]) -> (u16, String) {
+    if before.is_empty() {
+        reply("You gave an answer too recently; you have to wait.  You have 1s left to wait. <a href=\"/\">[Return]</a>")
+    } else {
+        reply("That's the right answer!")
+    }
+}
+
+fn client(server: &StubServer) -> Client {
+    Client::new(server.url(), Some("secret".to_string()))
+}
+
+#[test]
+fn test_correct_answer_is_recorded() {
+    let server = StubServer::start(judge);
+    let mut store = AnswerStore::default();
+
+    let submission = client(&server).submit(&mut store, 5, Part::One, "00ff", "143", Duration::ZERO).unwrap();
+    assert_eq!(submission, Submission::Sent(Outcome::Judged(Verdict::Correct)));
+    assert_eq!(store.lookup(2024, 5, Part::One, "00ff").unwrap().expected, "143");
+
+    let seen = server.seen();
+    assert_eq!((seen[0].method.as_str(), seen[0].url.as_str()), ("POST", "/2024/day/5/answer"));
+    assert_eq!(seen[0].cookie.as_deref(), Some("session=secret"));
+}
+
+#[test]
+fn test_known_wrong_answers_are_not_resubmitted() {
+    let server = StubServer::start(judge);
+    let client = client(&server);
+    let mut store = AnswerStore::default();
+
+    let too_high = client.submit(&mut store, 5, Part::One, "00ff", "900", Duration::ZERO).unwrap();
+    assert_eq!(too_high, Submission::Sent(Outcome::Judged(Verdict::TooHigh)));
+    let too_low = client.submit(&mut store, 5, Part::One, "00ff", "100", Duration::ZERO).unwrap();
+    assert_eq!(too_low, Submission::Sent(Outcome::Judged(Verdict::TooLow)));
+    assert_eq!(store.rejected().len(), 2);
+    assert_eq!(server.seen().len(), 2);
+
+    // The same answers, and anything beyond them, are refused locally
+    for (answer, verdict) in [("900", Verdict::TooHigh), ("1000", Verdict::TooHigh), ("99", Verdict::TooLow)] {
+        let known = client.submit(&mut store, 5, Part::One, "00ff", answer, Duration::ZERO).unwrap();
+        assert_eq!(known, Submission::Known(verdict));
+    }
+    assert_eq!(server.seen().len(), 2);
+
+    // As is resubmitting an answer once the right one is known
+    client.submit(&mut store, 5, Part::One, "00ff", "143", Duration::ZERO).unwrap();
+    let known = client.submit(&mut store, 5, Part::One, "00ff", "143", Duration::ZERO).unwrap();
+    assert_eq!(known, Submission::Known(Verdict::Correct));
+    assert_eq!(server.seen().len(), 3);
+}
+
+#[test]
+fn test_verdicts_are_kept_per_year() {
+    let server = StubServer::start(judge);
+    let mut store = AnswerStore::default();
+    let client = client(&server).with_year(2025);
+
+    client.submit(&mut store, 5, Part::One, "00ff", "900", Duration::ZERO).unwrap();
+    assert_eq!(store.rejected()[0].year, 2025);
+    assert_eq!(server.seen()[0].url, "/2025/day/5/answer");
+
+    // The same day, part and input in another year is a different puzzle
+    let other = client.with_year(2024).submit(&mut store, 5, Part::One, "00ff", "900", Duration::ZERO).unwrap();
+    assert_eq!(other, Submission::Sent(Outcome::Judged(Verdict::TooHigh)));
+    assert_eq!(server.seen().len(), 2);
+}
+
+#[test]
+fn test_answers_the_store_cannot_hold_are_not_sent() {
+    let server = StubServer::start(judge);
+    let mut store = AnswerStore::default();
+    for answer in ["", "143 144"] {
+        let err = client(&server).submit(&mut store, 5, Part::One, "00ff", answer, Duration::ZERO).unwrap_err();
+        assert!(matches!(err, ClientError::Answers(_)));
+    }
+    assert!(server.seen().is_empty());
+    assert_eq!(store, AnswerStore::default());
+}
+
+#[test]
+fn test_wrong_level_records_nothing() {
+    let server = StubServer::start(judge);
+    let mut store = AnswerStore::default();
+    let submission = client(&server).submit(&mut store, 5, Part::Two, "00ff", "143", Duration::ZERO).unwrap();
+    assert_eq!(submission, Submission::Sent(Outcome::WrongLevel));
+    assert_eq!(store, AnswerStore::default());
+}
+
+#[test]
+fn test_rate_limit_is_reported_without_a_wait_budget() {
+    let server = StubServer::start(rate_limited);
+    let mut store = AnswerStore::default();
+    let submission = client(&server).submit(&mut store, 5, Part::One, "00ff", "143", Duration::ZERO).unwrap();
+    assert_eq!(submission, Submission::Sent(Outcome::RateLimited(Duration::from_secs(1))));
+    assert_eq!(server.seen().len(), 1);
+}
+
+#[test]
+fn test_rate_limit_is_waited_out() {
+    let server = StubServer::start(rate_limited);
+    let mut store = AnswerStore::default();
+    let submission = client(&server).submit(&mut store, 5, Part::One, "00ff", "143", Duration::from_secs(5)).unwrap();
+    assert_eq!(submission, Submission::Sent(Outcome::Judged(Verdict::Correct)));
+    assert_eq!(server.seen().len(), 2);
+}
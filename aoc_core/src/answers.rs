@@ -0,0 +1,371 @@
+use crate::{workspace_root, Part};
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+const HEADER: &str = "# year day part input_hash expected";
+const REJECTED_HEADER: &str = "# year day part input_hash rejected too_high|too_low|wrong";
+
+/// Stable 64-bit FNV-1a hash of a puzzle input, as 16 hex digits.
+///
+/// Used to tell apart answers for different inputs of the same day.
+pub fn input_hash(input: &str) -> String {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for byte in input.bytes() {
+        hash ^= u64::from(byte);
+        hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+    }
+    format!("{:016x}", hash)
+}
+
+/// Location of the answers file: `answers.txt` at the workspace root.
+pub fn default_answers_path() -> PathBuf {
+    workspace_root().join("answers.txt")
+}
+
+/// Checks that `answer` fits in one field of the answers file, whose fields
+/// are separated by whitespace: it must be non-empty and contain none.
+pub fn check_answer(answer: &str) -> Result<(), AnswersError> {
+    if answer.is_empty() || answer.contains(char::is_whitespace) {
+        return Err(AnswersError::InvalidAnswer { answer: answer.to_string() });
+    }
+    Ok(())
+}
+
+/// A confirmed answer for one part of one day's input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KnownAnswer {
+    pub year: u16,
+    pub day: u8,
+    pub part: Part,
+    pub input_hash: String,
+    pub expected: String,
+}
+
+/// What the puzzle site said about a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint which way.
+    Wrong,
+}
+
+impl Verdict {
+    pub fn name(self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too_high",
+            Verdict::TooLow => "too_low",
+            Verdict::Wrong => "wrong",
+        }
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too high",
+            Verdict::TooLow => "too low",
+            Verdict::Wrong => "wrong",
+        })
+    }
+}
+
+/// An answer the puzzle site turned down for one part of one day's input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RejectedAnswer {
+    pub year: u16,
+    pub day: u8,
+    pub part: Part,
+    pub input_hash: String,
+    pub answer: String,
+    /// [`Verdict::TooHigh`], [`Verdict::TooLow`] or [`Verdict::Wrong`].
+    pub verdict: Verdict,
+}
+
+#[derive(Debug)]
+pub enum AnswersError {
+    Io { path: PathBuf, error: io::Error },
+    Malformed { line: usize, message: String },
+    /// An answer that cannot be written as one field of the file.
+    InvalidAnswer { answer: String },
+}
+
+impl fmt::Display for AnswersError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AnswersError::Io { path, error } => write!(f, "failed to access {}: {}", path.display(), error),
+            AnswersError::Malformed { line, message } => write!(f, "answers file line {}: {}", line, message),
+            AnswersError::InvalidAnswer { answer } => {
+                write!(f, "answer {:?} must be non-empty and contain no whitespace", answer)
+            }
+        }
+    }
+}
+
+impl std::error::Error for AnswersError {}
+
+/// The known-answers file: one `year day part input_hash expected` entry per
+/// line, with `#` starting a comment line.
+///
+/// Answers the site rejected are kept too, as `year day part input_hash
+/// answer verdict` lines with a verdict of `too_high`, `too_low` or
+/// `wrong`, so they are never submitted twice.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AnswerStore {
+    entries: Vec<KnownAnswer>,
+    rejected: Vec<RejectedAnswer>,
+}
+
+impl AnswerStore {
+    /// Reads the store from `path`; a missing file is an empty store.
+    pub fn load(path: &Path) -> Result<AnswerStore, AnswersError> {
+        match fs::read_to_string(path) {
+            Ok(text) => AnswerStore::parse(&text),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(AnswerStore::default()),
+            Err(error) => Err(AnswersError::Io { path: path.to_path_buf(), error }),
+        }
+    }
+
+    pub fn parse(text: &str) -> Result<AnswerStore, AnswersError> {
+        let mut store = AnswerStore::default();
+        for (i, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let malformed = |message: &str| AnswersError::Malformed { line: i + 1, message: message.to_string() };
+
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let (year, day, part, input_hash, answer, verdict) = match fields[..] {
+                [year, day, part, input_hash, expected] => (year, day, part, input_hash, expected, None),
+                [year, day, part, input_hash, answer, verdict] => (year, day, part, input_hash, answer, Some(verdict)),
+                _ => return Err(malformed("expected 'year day part input_hash expected'")),
+            };
+            let year = year.parse().map_err(|_| malformed("invalid year"))?;
+            let day = day.parse().map_err(|_| malformed("invalid day"))?;
+            let part = part.parse().ok().and_then(Part::from_number).ok_or_else(|| malformed("part must be 1 or 2"))?;
+            let input_hash = input_hash.to_string();
+            let answer = answer.to_string();
+            match verdict {
+                None => store.entries.push(KnownAnswer { year, day, part, input_hash, expected: answer }),
+                Some(verdict) => {
+                    let verdict = match verdict {
+                        "too_high" => Verdict::TooHigh,
+                        "too_low" => Verdict::TooLow,
+                        "wrong" => Verdict::Wrong,
+                        _ => return Err(malformed("verdict must be too_high, too_low or wrong")),
+                    };
+                    store.rejected.push(RejectedAnswer { year, day, part, input_hash, answer, verdict });
+                }
+            }
+        }
+        Ok(store)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), AnswersError> {
+        fs::write(path, self.to_string()).map_err(|error| AnswersError::Io { path: path.to_path_buf(), error })
+    }
+
+    pub fn entries(&self) -> &[KnownAnswer] {
+        &self.entries
+    }
+
+    /// The known answer for `part` of `day` of `year` on the input with
+    /// `input_hash`.
+    pub fn lookup(&self, year: u16, day: u8, part: Part, input_hash: &str) -> Option<&KnownAnswer> {
+        self.entries.iter().find(|entry| {
+            entry.year == year && entry.day == day && entry.part == part && entry.input_hash == input_hash
+        })
+    }
+
+    /// Adds `answer`, replacing any entry for the same year, day, part and
+    /// input; fails if the answer could not be read back (see
+    /// [`check_answer`]).
+    pub fn record(&mut self, answer: KnownAnswer) -> Result<(), AnswersError> {
+        check_answer(&answer.expected)?;
+        self.entries.retain(|entry| {
+            !(entry.year == answer.year
+                && entry.day == answer.day
+                && entry.part == answer.part
+                && entry.input_hash == answer.input_hash)
+        });
+        self.entries.push(answer);
+        self.entries.sort_by_key(|entry| (entry.year, entry.day, entry.part));
+        Ok(())
+    }
+
+    /// Answers rejected so far, in file order.
+    pub fn rejected(&self) -> &[RejectedAnswer] {
+        &self.rejected
+    }
+
+    /// Adds a rejected answer, unless the same answer is already on file;
+    /// fails like [`AnswerStore::record`].
+    pub fn reject(&mut self, rejected: RejectedAnswer) -> Result<(), AnswersError> {
+        check_answer(&rejected.answer)?;
+        let known = self.rejected.iter().any(|entry| {
+            entry.year == rejected.year
+                && entry.day == rejected.day
+                && entry.part == rejected.part
+                && entry.input_hash == rejected.input_hash
+                && entry.answer == rejected.answer
+        });
+        if !known {
+            self.rejected.push(rejected);
+            self.rejected.sort_by_key(|entry| (entry.year, entry.day, entry.part));
+        }
+        Ok(())
+    }
+
+    /// What the site would say about `answer` for `part` of `day` of `year`
+    /// on the input with `input_hash`, as far as the store can tell: the
+    /// known answer decides outright, and numeric answers are also judged
+    /// against the bounds earlier `too_high` and `too_low` verdicts give.
+    pub fn judge(&self, year: u16, day: u8, part: Part, input_hash: &str, answer: &str) -> Option<Verdict> {
+        if let Some(known) = self.lookup(year, day, part, input_hash) {
+            return Some(if known.expected == answer { Verdict::Correct } else { Verdict::Wrong });
+        }
+
+        let number = answer.parse::<i64>().ok();
+        self.rejected
+            .iter()
+            .filter(|entry| {
+                entry.year == year && entry.day == day && entry.part == part && entry.input_hash == input_hash
+            })
+            .find_map(|entry| {
+                let bound = entry.answer.parse::<i64>().ok();
+                match (entry.verdict, number, bound) {
+                    _ if entry.answer == answer => Some(entry.verdict),
+                    (Verdict::TooHigh, Some(n), Some(bound)) if n >= bound => Some(Verdict::TooHigh),
+                    (Verdict::TooLow, Some(n), Some(bound)) if n <= bound => Some(Verdict::TooLow),
+                    _ => None,
+                }
+            })
+    }
+}
+
+impl fmt::Display for AnswerStore {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}", HEADER)?;
+        for entry in &self.entries {
+            writeln!(f, "{} {} {} {} {}", entry.year, entry.day, entry.part, entry.input_hash, entry.expected)?;
+        }
+        if !self.rejected.is_empty() {
+            writeln!(f, "{}", REJECTED_HEADER)?;
+        }
+        for entry in &self.rejected {
+            writeln!(
+                f,
+                "{} {} {} {} {} {}",
+                entry.year,
+                entry.day,
+                entry.part,
+                entry.input_hash,
+                entry.answer,
+                entry.verdict.name()
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_hash_is_fnv1a() {
+        assert_eq!(input_hash(""), "cbf29ce484222325");
+        assert_eq!(input_hash("a"), "af63dc4c8601ec8c");
+    }
+
+    #[test]
+    fn test_store_round_trips() {
+        let text = "# year day part input_hash expected\n2024 5 1 00ff 143\n2024 5 2 00ff 123\n";
+        let store = AnswerStore::parse(text).unwrap();
+        assert_eq!(store.lookup(2024, 5, Part::Two, "00ff").unwrap().expected, "123");
+        assert_eq!(store.lookup(2024, 5, Part::Two, "abcd"), None);
+        assert_eq!(store.lookup(2025, 5, Part::Two, "00ff"), None);
+        assert_eq!(store.to_string(), text);
+    }
+
+    #[test]
+    fn test_the_year_is_required() {
+        for line in ["5 1 00ff 143\n", "5 2 00ff 500 too_high\n", "x 5 1 00ff 143\n"] {
+            assert!(matches!(AnswerStore::parse(line), Err(AnswersError::Malformed { line: 1, .. })), "{}", line);
+        }
+    }
+
+    #[test]
+    fn test_record_replaces_existing_entry() {
+        let mut store = AnswerStore::parse("2024 7 1 aa 1\n").unwrap();
+        let answer = |year, expected: &str| KnownAnswer {
+            year,
+            day: 7,
+            part: Part::One,
+            input_hash: "aa".into(),
+            expected: expected.into(),
+        };
+        store.record(answer(2024, "2")).unwrap();
+        assert_eq!(store.entries().len(), 1);
+        assert_eq!(store.entries()[0].expected, "2");
+        store.record(answer(2025, "3")).unwrap();
+        assert_eq!(store.entries().len(), 2);
+    }
+
+    #[test]
+    fn test_answers_that_would_not_read_back_are_refused() {
+        let mut store = AnswerStore::default();
+        for answer in ["", "1 2", "12\n"] {
+            let (input_hash, answer) = ("aa".to_string(), answer.to_string());
+            let expected = answer.clone();
+            let known = KnownAnswer { year: 2024, day: 7, part: Part::One, input_hash: input_hash.clone(), expected };
+            assert!(matches!(store.record(known), Err(AnswersError::InvalidAnswer { .. })));
+            let verdict = Verdict::Wrong;
+            let rejected = RejectedAnswer { year: 2024, day: 7, part: Part::One, input_hash, answer, verdict };
+            assert!(matches!(store.reject(rejected), Err(AnswersError::InvalidAnswer { .. })));
+        }
+        assert_eq!(store, AnswerStore::default());
+    }
+
+    #[test]
+    fn test_rejected_answers_round_trip() {
+        let text = "# year day part input_hash expected\n2024 5 1 00ff 143\n\
+                    # year day part input_hash rejected too_high|too_low|wrong\n\
+                    2024 5 2 00ff 500 too_high\n2024 5 2 00ff abc wrong\n";
+        let store = AnswerStore::parse(text).unwrap();
+        assert_eq!(store.rejected().len(), 2);
+        assert_eq!(store.to_string(), text);
+        assert!(AnswerStore::parse("2024 5 2 00ff 500 too_big\n").is_err());
+    }
+
+    #[test]
+    fn test_judge_uses_known_answers_and_bounds() {
+        let mut store = AnswerStore::parse("2024 5 1 00ff 143\n").unwrap();
+        for (answer, verdict) in [("500", Verdict::TooHigh), ("100", Verdict::TooLow), ("250", Verdict::Wrong)] {
+            let (input_hash, answer) = ("00ff".to_string(), answer.to_string());
+            let rejected = RejectedAnswer { year: 2024, day: 5, part: Part::Two, input_hash, answer, verdict };
+            store.reject(rejected).unwrap();
+        }
+
+        assert_eq!(store.judge(2024, 5, Part::One, "00ff", "143"), Some(Verdict::Correct));
+        assert_eq!(store.judge(2024, 5, Part::One, "00ff", "144"), Some(Verdict::Wrong));
+        assert_eq!(store.judge(2024, 5, Part::Two, "00ff", "600"), Some(Verdict::TooHigh));
+        assert_eq!(store.judge(2024, 5, Part::Two, "00ff", "100"), Some(Verdict::TooLow));
+        assert_eq!(store.judge(2024, 5, Part::Two, "00ff", "250"), Some(Verdict::Wrong));
+        assert_eq!(store.judge(2024, 5, Part::Two, "00ff", "300"), None);
+        assert_eq!(store.judge(2024, 5, Part::Two, "abcd", "600"), None);
+        assert_eq!(store.judge(2025, 5, Part::Two, "00ff", "600"), None);
+    }
+
+    #[test]
+    fn test_parse_rejects_bad_part() {
+        let err = AnswerStore::parse("\n2024 5 3 00ff 1\n").unwrap_err();
+        assert!(matches!(err, AnswersError::Malformed { line: 2, .. }));
+    }
+}
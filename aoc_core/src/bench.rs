@@ -0,0 +1,191 @@
+use crate::{solve_within, ParseError, Part, Solver, TimeBudget};
+use std::fmt;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// A timed stage of solving a day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Phase {
+    Parse,
+    Part(Part),
+}
+
+impl Phase {
+    pub const ALL: [Phase; 3] = [Phase::Parse, Phase::Part(Part::One), Phase::Part(Part::Two)];
+
+    /// Short stable name, used in machine-readable output.
+    pub fn name(self) -> &'static str {
+        match self {
+            Phase::Parse => "parse",
+            Phase::Part(Part::One) => "part1",
+            Phase::Part(Part::Two) => "part2",
+        }
+    }
+}
+
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Summary of the samples taken for one phase.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+}
+
+impl Stats {
+    /// Summarises `samples`, which must not be empty.
+    pub fn from_samples(samples: &[Duration]) -> Stats {
+        assert!(!samples.is_empty(), "cannot summarise zero samples");
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let mid = sorted.len() / 2;
+        let median = if sorted.len().is_multiple_of(2) {
+            (sorted[mid - 1] + sorted[mid]) / 2
+        } else {
+            sorted[mid]
+        };
+        let total: Duration = sorted.iter().sum();
+
+        Stats {
+            min: sorted[0],
+            median,
+            mean: total / sorted.len() as u32,
+        }
+    }
+}
+
+/// Timings of every phase of one day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BenchReport {
+    pub year: u16,
+    pub day: u8,
+    pub iterations: usize,
+    pub phases: Vec<(Phase, Stats)>,
+}
+
+/// Why [`bench`] could not time a day.
+#[derive(Debug)]
+pub enum BenchError {
+    Parse(ParseError),
+    /// A part ran out of the time `budget` gives it.
+    TimedOut { year: u16, day: u8, part: Part, limit: Duration },
+}
+
+impl BenchError {
+    /// Formats the error for the terminal, like [`ParseError::render`].
+    pub fn render(&self) -> String {
+        match self {
+            BenchError::Parse(err) => err.render(),
+            BenchError::TimedOut { year, day, part, limit } => {
+                format!("error: {} day {} part {} timed out after {:.2?}", year, day, part, limit)
+            }
+        }
+    }
+}
+
+impl From<ParseError> for BenchError {
+    fn from(err: ParseError) -> BenchError {
+        BenchError::Parse(err)
+    }
+}
+
+/// Runs parse, part one and part two of `solver` on `input` `iterations`
+/// times each and summarises how long every phase took.
+///
+/// Every run of a part gets the time `budget` allows it; the first one to
+/// run out ends the benchmark.
+pub fn bench(
+    solver: &dyn Solver,
+    input: &str,
+    iterations: usize,
+    budget: &TimeBudget,
+) -> Result<BenchReport, BenchError> {
+    assert!(iterations > 0, "need at least one iteration");
+    let mut samples = vec![Vec::with_capacity(iterations); Phase::ALL.len()];
+
+    for _ in 0..iterations {
+        let start = Instant::now();
+        let parsed = black_box(solver.parse(black_box(input))?);
+        samples[0].push(start.elapsed());
+
+        for (i, part) in Part::ALL.into_iter().enumerate() {
+            let limit = budget.limit(solver.year(), solver.day(), part);
+            let start = Instant::now();
+            let answer = black_box(solve_within(solver, &parsed, part, limit));
+            samples[i + 1].push(start.elapsed());
+            if let (None, Some(limit)) = (answer, limit) {
+                return Err(BenchError::TimedOut { year: solver.year(), day: solver.day(), part, limit });
+            }
+        }
+    }
+
+    Ok(BenchReport {
+        year: solver.year(),
+        day: solver.day(),
+        iterations,
+        phases: Phase::ALL
+            .into_iter()
+            .zip(samples.iter().map(|s| Stats::from_samples(s)))
+            .collect(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(n: u64) -> Duration {
+        Duration::from_millis(n)
+    }
+
+    #[test]
+    fn test_stats_odd_number_of_samples() {
+        let stats = Stats::from_samples(&[ms(5), ms(1), ms(3)]);
+        assert_eq!(stats, Stats { min: ms(1), median: ms(3), mean: ms(3) });
+    }
+
+    #[test]
+    fn test_stats_even_number_of_samples() {
+        let stats = Stats::from_samples(&[ms(4), ms(1), ms(2), ms(9)]);
+        assert_eq!(stats, Stats { min: ms(1), median: ms(3), mean: ms(4) });
+    }
+
+    #[test]
+    fn test_parts_are_held_to_the_budget() {
+        use crate::{cancelled, Answer, Solution};
+
+        /// Part two runs until it is cancelled.
+        struct Slow;
+
+        impl Solution for Slow {
+            const YEAR: u16 = 2024;
+            const DAY: u8 = 1;
+            type Input = ();
+
+            fn parse(_: &str) -> Result<(), ParseError> {
+                Ok(())
+            }
+
+            fn part_one(_: &()) -> Answer {
+                Answer::from(1i64)
+            }
+
+            fn part_two(_: &()) -> Answer {
+                while !cancelled() {
+                    std::thread::sleep(ms(1));
+                }
+                Answer::from(2i64)
+            }
+        }
+
+        let budget = TimeBudget::new(vec!["1.2=20ms".parse().unwrap()]);
+        let err = bench(&Slow, "", 3, &budget).unwrap_err();
+        assert!(matches!(err, BenchError::TimedOut { day: 1, part: Part::Two, .. }));
+    }
+}
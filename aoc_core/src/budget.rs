@@ -0,0 +1,170 @@
+use crate::Part;
+use std::fmt;
+use std::str::FromStr;
+use std::time::Duration;
+
+/// Parses a duration such as `500ms`, `30s` or `2m`; a bare number is in
+/// seconds.
+pub fn parse_duration(s: &str) -> Result<Duration, String> {
+    let invalid = || format!("invalid duration '{}' (expected e.g. 500ms, 30s or 2m)", s);
+    let (number, unit) = match s.find(|c: char| !c.is_ascii_digit() && c != '.') {
+        Some(i) => s.split_at(i),
+        None => (s, "s"),
+    };
+    let number: f64 = number.parse().map_err(|_| invalid())?;
+    let seconds = match unit {
+        "ms" => number / 1000.0,
+        "s" => number,
+        "m" => number * 60.0,
+        _ => return Err(invalid()),
+    };
+    Duration::try_from_secs_f64(seconds).map_err(|_| invalid())
+}
+
+/// One `--timeout` setting: a limit for every part, for both parts of one
+/// day (`7=2m`) or for one part of one day (`7.2=2m`), in every year or,
+/// prefixed with it, in one year only (`2025/7=2m`, `2025/7.2=2m`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BudgetRule {
+    /// Only ever set along with `day`.
+    pub year: Option<u16>,
+    pub day: Option<u8>,
+    pub part: Option<Part>,
+    pub limit: Duration,
+}
+
+impl FromStr for BudgetRule {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<BudgetRule, String> {
+        let Some((target, limit)) = s.split_once('=') else {
+            return Ok(BudgetRule { year: None, day: None, part: None, limit: parse_duration(s)? });
+        };
+        let invalid = || {
+            format!("invalid timeout target '{}' (expected DAY, DAY.PART, YEAR/DAY or YEAR/DAY.PART)", target)
+        };
+        let (year, target) = match target.split_once('/') {
+            Some((year, target)) => (Some(year.parse().map_err(|_| invalid())?), target),
+            None => (None, target),
+        };
+        let (day, part) = match target.split_once('.') {
+            Some((day, part)) => {
+                let part = part.parse().ok().and_then(Part::from_number).ok_or_else(invalid)?;
+                (day, Some(part))
+            }
+            None => (target, None),
+        };
+        let day = day.parse().map_err(|_| invalid())?;
+        Ok(BudgetRule { year, day: Some(day), part, limit: parse_duration(limit)? })
+    }
+}
+
+/// Writes the rule back in the form [`BudgetRule::from_str`] reads.
+impl fmt::Display for BudgetRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(year) = self.year {
+            write!(f, "{}/", year)?;
+        }
+        match (self.day, self.part) {
+            (Some(day), Some(part)) => write!(f, "{}.{}=", day, part)?,
+            (Some(day), None) => write!(f, "{}=", day)?,
+            _ => {}
+        }
+        if self.limit.subsec_nanos() == 0 {
+            write!(f, "{}s", self.limit.as_secs())
+        } else {
+            write!(f, "{}ms", self.limit.as_secs_f64() * 1000.0)
+        }
+    }
+}
+
+/// How long each part may run before it is cancelled.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TimeBudget {
+    rules: Vec<BudgetRule>,
+}
+
+impl TimeBudget {
+    /// A budget from `rules`; the most specific rule for a part wins, the
+    /// one naming the most of year, day and part, and later rules beat
+    /// earlier ones that are just as specific.
+    pub fn new(rules: Vec<BudgetRule>) -> TimeBudget {
+        TimeBudget { rules }
+    }
+
+    /// The time allowed for `part` of `day` of `year`, or `None` for no
+    /// limit.
+    pub fn limit(&self, year: u16, day: u8, part: Part) -> Option<Duration> {
+        self.rules
+            .iter()
+            .filter(|rule| {
+                rule.year.is_none_or(|y| y == year)
+                    && rule.day.is_none_or(|d| d == day)
+                    && rule.part.is_none_or(|p| p == part)
+            })
+            .max_by_key(|rule| {
+                usize::from(rule.year.is_some()) + usize::from(rule.day.is_some()) + usize::from(rule.part.is_some())
+            })
+            .map(|rule| rule.limit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_duration() {
+        assert_eq!(parse_duration("250ms"), Ok(Duration::from_millis(250)));
+        assert_eq!(parse_duration("1.5s"), Ok(Duration::from_millis(1500)));
+        assert_eq!(parse_duration("2m"), Ok(Duration::from_secs(120)));
+        assert_eq!(parse_duration("3"), Ok(Duration::from_secs(3)));
+        assert!(parse_duration("3h").is_err());
+        assert!(parse_duration("s").is_err());
+    }
+
+    #[test]
+    fn test_most_specific_rule_wins() {
+        let rules = ["10s", "7=1m", "7.2=5m", "6.1=1s"].iter().map(|s| s.parse().unwrap()).collect();
+        let budget = TimeBudget::new(rules);
+        assert_eq!(budget.limit(2024, 1, Part::One), Some(Duration::from_secs(10)));
+        assert_eq!(budget.limit(2024, 7, Part::One), Some(Duration::from_secs(60)));
+        assert_eq!(budget.limit(2024, 7, Part::Two), Some(Duration::from_secs(300)));
+        assert_eq!(budget.limit(2024, 6, Part::Two), Some(Duration::from_secs(10)));
+        assert_eq!(TimeBudget::default().limit(2024, 7, Part::Two), None);
+    }
+
+    #[test]
+    fn test_rules_can_name_a_year() {
+        let rules = ["10s", "2025/7=1m", "7.2=5m", "2025/7.2=1s"].iter().map(|s| s.parse().unwrap()).collect();
+        let budget = TimeBudget::new(rules);
+        assert_eq!(budget.limit(2024, 7, Part::One), Some(Duration::from_secs(10)));
+        assert_eq!(budget.limit(2025, 7, Part::One), Some(Duration::from_secs(60)));
+        assert_eq!(budget.limit(2024, 7, Part::Two), Some(Duration::from_secs(300)));
+        assert_eq!(budget.limit(2025, 7, Part::Two), Some(Duration::from_secs(1)));
+    }
+
+    #[test]
+    fn test_later_rules_override_earlier_ones() {
+        let budget = TimeBudget::new(vec!["7=1s".parse().unwrap(), "7=2s".parse().unwrap()]);
+        assert_eq!(budget.limit(2024, 7, Part::One), Some(Duration::from_secs(2)));
+    }
+
+    #[test]
+    fn test_rules_display_as_they_parse() {
+        for rule in ["30s", "7=2s", "7.2=250ms", "1.2=0.5ms", "2025/7=2s", "2025/7.2=1s"] {
+            let parsed: BudgetRule = rule.parse().unwrap();
+            assert_eq!(parsed.to_string(), rule);
+            assert_eq!(parsed.to_string().parse(), Ok(parsed));
+        }
+    }
+
+    #[test]
+    fn test_bad_rules_are_rejected() {
+        assert!("7.3=1s".parse::<BudgetRule>().is_err());
+        assert!("x=1s".parse::<BudgetRule>().is_err());
+        assert!("7=".parse::<BudgetRule>().is_err());
+        assert!("2025/=1s".parse::<BudgetRule>().is_err());
+        assert!("x/7=1s".parse::<BudgetRule>().is_err());
+    }
+}
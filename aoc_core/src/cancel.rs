@@ -0,0 +1,82 @@
+use std::cell::RefCell;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+/// A shared flag asking running work to stop early.
+///
+/// Cancellation is cooperative: long-running solvers call [`cancelled`]
+/// every so often and return whatever they have when it says so. The
+/// caller that cancelled knows to throw that result away.
+#[derive(Debug, Clone, Default)]
+pub struct CancelToken(Arc<AtomicBool>);
+
+impl CancelToken {
+    pub fn new() -> CancelToken {
+        CancelToken::default()
+    }
+
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+thread_local! {
+    static CURRENT: RefCell<Option<CancelToken>> = const { RefCell::new(None) };
+}
+
+/// Runs `f` with `token` as this thread's current token, restoring the
+/// previous one afterwards.
+pub fn with_cancel_token<R>(token: &CancelToken, f: impl FnOnce() -> R) -> R {
+    let previous = CURRENT.with(|current| current.replace(Some(token.clone())));
+    // Restore on the way out even if `f` panics
+    struct Restore(Option<CancelToken>);
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            CURRENT.with(|current| *current.borrow_mut() = self.0.take());
+        }
+    }
+    let _restore = Restore(previous);
+    f()
+}
+
+/// This thread's current token, if any; see [`with_cancel_token`].
+pub fn current_cancel_token() -> Option<CancelToken> {
+    CURRENT.with(|current| current.borrow().clone())
+}
+
+/// Whether the work running on this thread has been asked to stop. Cheap
+/// enough to call in inner loops.
+pub fn cancelled() -> bool {
+    CURRENT.with(|current| current.borrow().as_ref().is_some_and(CancelToken::is_cancelled))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cancelled_follows_the_current_token() {
+        assert!(!cancelled());
+        let token = CancelToken::new();
+        with_cancel_token(&token, || {
+            assert!(!cancelled());
+            token.cancel();
+            assert!(cancelled());
+        });
+        assert!(!cancelled());
+    }
+
+    #[test]
+    fn test_tokens_nest() {
+        let outer = CancelToken::new();
+        outer.cancel();
+        with_cancel_token(&outer, || {
+            with_cancel_token(&CancelToken::new(), || assert!(!cancelled()));
+            assert!(cancelled());
+        });
+    }
+}
@@ -0,0 +1,105 @@
+use crate::{
+    load_input, print_answers, set_progress_mode, set_threads, BudgetRule, InputSource, Part, ProgressMode, Solver,
+    TimeBudget,
+};
+use std::env;
+use std::process::ExitCode;
+
+const USAGE: &str = "usage: [--part <1|2>] [--input <path>|-] [--threads <n>] [--progress <quiet|bar|log>] [--timeout <limit>]";
+
+/// Entry point shared by the per-day binaries.
+///
+/// Accepts `--part <1|2>` and `--input <path>` (`-` reads stdin), falling back
+/// to the default inputs directory, and `--threads <n>` to size the pool used
+/// by [`par_map`](crate::par_map). `--progress <quiet|bar|log>` picks how
+/// progress is reported, by default a bar when stderr is a terminal.
+/// `--timeout <limit>` (e.g. `30s`, or `2=1m` for part two only; repeatable)
+/// gives up on parts that run longer, and the binary then fails.
+pub fn main(solver: &dyn Solver) -> ExitCode {
+    let mut part = None;
+    let mut source = InputSource::Default;
+    let mut rules = Vec::new();
+    set_progress_mode(ProgressMode::auto());
+
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-p" | "--part" => match args.next().as_deref().and_then(|n| n.parse().ok()).and_then(Part::from_number) {
+                Some(p) => part = Some(p),
+                None => return usage_error("--part expects 1 or 2"),
+            },
+            "-i" | "--input" => match args.next() {
+                Some(value) => source = InputSource::from_arg(&value),
+                None => return usage_error("--input expects a path, or - for stdin"),
+            },
+            "-t" | "--threads" => match args.next().and_then(|n| n.parse().ok()) {
+                Some(threads) => {
+                    if let Err(message) = set_threads(threads) {
+                        return usage_error(&message);
+                    }
+                }
+                None => return usage_error("--threads expects a number"),
+            },
+            "--progress" => match args.next().map(|mode| mode.parse()) {
+                Some(Ok(mode)) => set_progress_mode(mode),
+                Some(Err(message)) => return usage_error(&message),
+                None => return usage_error("--progress expects quiet, bar or log"),
+            },
+            "--timeout" => match args.next().map(|limit| parse_timeout(solver.day(), &limit)) {
+                Some(Ok(rule)) => rules.push(rule),
+                Some(Err(message)) => return usage_error(&message),
+                None => return usage_error("--timeout expects a limit such as 30s"),
+            },
+            other => return usage_error(&format!("unexpected argument '{}'", other)),
+        }
+    }
+
+    let input = match load_input(solver.year(), solver.day(), &source) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+    match print_answers(solver, &input, part, &TimeBudget::new(rules)) {
+        Ok(0) => ExitCode::SUCCESS,
+        Ok(_) => ExitCode::FAILURE,
+        Err(err) => {
+            eprintln!("{}", err.render());
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Parses a `--timeout` value: a limit for every part, or `PART=LIMIT`
+/// since a day binary only has the one day. Rules naming a day, as the
+/// `aoc` runner takes them, are rejected rather than misread.
+fn parse_timeout(day: u8, value: &str) -> Result<BudgetRule, String> {
+    match value.split_once('=') {
+        Some((part @ ("1" | "2"), limit)) => format!("{}.{}={}", day, part, limit).parse(),
+        Some((target, _)) => Err(format!(
+            "--timeout expects LIMIT or PART=LIMIT with PART 1 or 2, not '{}': this binary only runs day {}",
+            target, day
+        )),
+        None => value.parse(),
+    }
+}
+
+fn usage_error(message: &str) -> ExitCode {
+    eprintln!("error: {}", message);
+    eprintln!("{}", USAGE);
+    ExitCode::FAILURE
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_timeouts_name_at_most_a_part() {
+        assert_eq!(parse_timeout(7, "1s").unwrap().to_string(), "1s");
+        assert_eq!(parse_timeout(7, "2=1s").unwrap().to_string(), "7.2=1s");
+        assert!(parse_timeout(7, "7.2=1s").is_err());
+        assert!(parse_timeout(7, "3=1s").is_err());
+    }
+}
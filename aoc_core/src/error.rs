@@ -0,0 +1,120 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// A problem found while parsing a day's puzzle input.
+///
+/// Lines and columns are 1-based; columns count characters, not bytes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: u8,
+    pub line: usize,
+    pub column: usize,
+    /// The offending piece of input, empty when something is missing.
+    pub text: String,
+    pub message: String,
+    /// The full input line the error points into.
+    pub source_line: String,
+}
+
+impl ParseError {
+    /// Builds an error pointing at `offending`, which must be a subslice of
+    /// `input` (as produced by `lines`, `split`, `split_whitespace`, ...).
+    pub fn at(day: u8, input: &str, offending: &str, message: impl Into<String>) -> ParseError {
+        let offset = (offending.as_ptr() as usize)
+            .checked_sub(input.as_ptr() as usize)
+            .filter(|&offset| offset <= input.len())
+            .expect("offending text must be a subslice of the input");
+
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        let line_end = input[offset..].find('\n').map_or(input.len(), |i| offset + i);
+
+        ParseError {
+            day,
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            text: offending.to_string(),
+            message: message.into(),
+            source_line: input[line_start..line_end].trim_end_matches('\r').to_string(),
+        }
+    }
+
+    /// Builds an error pointing just past the end of `input`, for input that
+    /// stops before something required.
+    pub fn at_end(day: u8, input: &str, message: impl Into<String>) -> ParseError {
+        ParseError::at(day, input, &input[input.len()..], message)
+    }
+
+    /// Formats the error with the offending line and a caret underneath, in the
+    /// style of compiler diagnostics.
+    pub fn render(&self) -> String {
+        let gutter = self.line.to_string().len();
+        let underline = "^".repeat(self.text.chars().count().max(1));
+        format!(
+            "error: {}\n{:gutter$}--> day {}, line {}, column {}\n{:gutter$} |\n{} | {}\n{:gutter$} | {}{}",
+            self.message,
+            "",
+            self.day,
+            self.line,
+            self.column,
+            "",
+            self.line,
+            self.source_line,
+            "",
+            " ".repeat(self.column - 1),
+            underline,
+        )
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "day {}, line {}, column {}: {}", self.day, self.line, self.column, self.message)?;
+        if !self.text.is_empty() {
+            write!(f, " ('{}')", self.text)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parses `token`, a subslice of `input`, as a number.
+pub fn parse_number<T: FromStr>(day: u8, input: &str, token: &str) -> Result<T, ParseError> {
+    token
+        .parse()
+        .map_err(|_| ParseError::at(day, input, token, "invalid number"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_at_points_to_line_and_column() {
+        let input = "1 2\n3 x4\n";
+        let token = input.split_whitespace().nth(3).unwrap();
+        let err = ParseError::at(5, input, token, "invalid number");
+        assert_eq!((err.line, err.column), (2, 3));
+        assert_eq!(err.text, "x4");
+        assert_eq!(err.source_line, "3 x4");
+    }
+
+    #[test]
+    fn test_at_end_points_past_last_line() {
+        let input = "1|2\n";
+        let err = ParseError::at_end(5, input, "missing updates");
+        assert_eq!((err.line, err.column), (2, 1));
+        assert_eq!(err.text, "");
+    }
+
+    #[test]
+    fn test_render_underlines_offending_text() {
+        let input = "ab\ncd ef";
+        let err = ParseError::at(4, input, &input[6..], "bad");
+        assert_eq!(
+            err.render(),
+            "error: bad\n --> day 4, line 2, column 4\n  |\n2 | cd ef\n  |    ^^"
+        );
+    }
+}
@@ -0,0 +1,286 @@
+use crate::{workspace_root, Part};
+use std::env;
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// Environment variable that overrides [`default_history_path`].
+pub const HISTORY_VAR: &str = "AOC_HISTORY";
+
+const HEADER: &str = "# timestamp revision profile threads year day part time_ns input_hash answer";
+
+/// Location of the run history: `$AOC_HISTORY` if set, otherwise
+/// `history.txt` at the workspace root.
+pub fn default_history_path() -> PathBuf {
+    match env::var_os(HISTORY_VAR) {
+        Some(path) => PathBuf::from(path),
+        None => workspace_root().join("history.txt"),
+    }
+}
+
+/// One solved part, as kept in the run history.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RunRecord {
+    /// Seconds since the Unix epoch.
+    pub timestamp: u64,
+    /// Git revision of the solvers, with `-dirty` for uncommitted changes.
+    pub revision: String,
+    /// `debug` or `release`.
+    pub profile: String,
+    pub threads: usize,
+    pub year: u16,
+    pub day: u8,
+    pub part: Part,
+    pub elapsed: Duration,
+    pub input_hash: String,
+    pub answer: String,
+}
+
+impl RunRecord {
+    /// Whether the two runs' times can be compared: same input, build
+    /// profile and number of threads.
+    pub fn comparable(&self, other: &RunRecord) -> bool {
+        self.input_hash == other.input_hash && self.profile == other.profile && self.threads == other.threads
+    }
+}
+
+#[derive(Debug)]
+pub enum HistoryError {
+    Io { path: PathBuf, error: io::Error },
+    Malformed { line: usize, message: String },
+}
+
+impl fmt::Display for HistoryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HistoryError::Io { path, error } => write!(f, "failed to access {}: {}", path.display(), error),
+            HistoryError::Malformed { line, message } => write!(f, "history file line {}: {}", line, message),
+        }
+    }
+}
+
+impl std::error::Error for HistoryError {}
+
+/// The run history: an append-only file with one
+/// `timestamp revision profile threads year day part time_ns input_hash answer`
+/// line per solved part, oldest first, and `#` starting a comment line.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct History {
+    records: Vec<RunRecord>,
+}
+
+impl History {
+    /// Reads the history from `path`; a missing file is an empty history.
+    pub fn load(path: &Path) -> Result<History, HistoryError> {
+        match fs::read_to_string(path) {
+            Ok(text) => History::parse(&text),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(History::default()),
+            Err(error) => Err(HistoryError::Io { path: path.to_path_buf(), error }),
+        }
+    }
+
+    pub fn parse(text: &str) -> Result<History, HistoryError> {
+        let mut records = Vec::new();
+        for (i, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let malformed = |message: &str| HistoryError::Malformed { line: i + 1, message: message.to_string() };
+
+            // The answer comes last and may contain spaces
+            let fields: Vec<&str> = line.splitn(10, ' ').collect();
+            let [timestamp, revision, profile, threads, year, day, part, time_ns, input_hash, answer] = fields[..]
+            else {
+                return Err(malformed("expected 10 fields"));
+            };
+            records.push(RunRecord {
+                timestamp: timestamp.parse().map_err(|_| malformed("invalid timestamp"))?,
+                revision: revision.to_string(),
+                profile: profile.to_string(),
+                threads: threads.parse().map_err(|_| malformed("invalid thread count"))?,
+                year: year.parse().map_err(|_| malformed("invalid year"))?,
+                day: day.parse().map_err(|_| malformed("invalid day"))?,
+                part: part.parse().ok().and_then(Part::from_number).ok_or_else(|| malformed("part must be 1 or 2"))?,
+                elapsed: Duration::from_nanos(time_ns.parse().map_err(|_| malformed("invalid time"))?),
+                input_hash: input_hash.to_string(),
+                answer: answer.to_string(),
+            });
+        }
+        Ok(History { records })
+    }
+
+    /// Appends `records` to the file at `path`, creating it if needed.
+    pub fn append(path: &Path, records: &[RunRecord]) -> Result<(), HistoryError> {
+        let io_error = |error| HistoryError::Io { path: path.to_path_buf(), error };
+        let is_new = !path.exists();
+        let mut file = OpenOptions::new().create(true).append(true).open(path).map_err(io_error)?;
+        let mut text = String::new();
+        if is_new {
+            text.push_str(HEADER);
+            text.push('\n');
+        }
+        for record in records {
+            text.push_str(&format!(
+                "{} {} {} {} {} {} {} {} {} {}\n",
+                record.timestamp,
+                record.revision,
+                record.profile,
+                record.threads,
+                record.year,
+                record.day,
+                record.part,
+                record.elapsed.as_nanos(),
+                record.input_hash,
+                record.answer
+            ));
+        }
+        // One write per batch keeps concurrent runs from interleaving lines
+        file.write_all(text.as_bytes()).map_err(io_error)
+    }
+
+    pub fn records(&self) -> &[RunRecord] {
+        &self.records
+    }
+
+    /// Runs of `part` of `day` of `year`, oldest first.
+    pub fn runs(&self, year: u16, day: u8, part: Part) -> Vec<&RunRecord> {
+        self.records
+            .iter()
+            .filter(|record| record.year == year && record.day == day && record.part == part)
+            .collect()
+    }
+}
+
+/// Something in a part's history worth a look.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Alert {
+    /// The latest run is `percent` slower than the median of the
+    /// comparable runs before it.
+    Slower { percent: f64, latest: Duration, baseline: Duration, runs: usize },
+    /// The answer for the same input changed between two runs.
+    AnswerChanged { from: String, to: String, revision: String },
+}
+
+impl fmt::Display for Alert {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Alert::Slower { percent, latest, baseline, runs } => write!(
+                f,
+                "{:.0}% slower than the median of the {} runs before ({:.2?} vs {:.2?})",
+                percent, runs, latest, baseline
+            ),
+            Alert::AnswerChanged { from, to, revision } => {
+                write!(f, "answer changed from {} to {} at {}", from, to, revision)
+            }
+        }
+    }
+}
+
+/// Alerts for the runs of one part, oldest first: answers that changed
+/// within the last `window` runs, and the latest run being more than
+/// `threshold` percent slower than the median of up to `window` comparable
+/// runs before it.
+pub fn alerts(runs: &[&RunRecord], window: usize, threshold: f64) -> Vec<Alert> {
+    let mut alerts = Vec::new();
+    let start = runs.len().saturating_sub(window);
+    for (i, run) in runs.iter().enumerate().skip(start) {
+        let previous = runs[..i].iter().rev().find(|earlier| earlier.input_hash == run.input_hash);
+        if let Some(previous) = previous.filter(|previous| previous.answer != run.answer) {
+            alerts.push(Alert::AnswerChanged {
+                from: previous.answer.clone(),
+                to: run.answer.clone(),
+                revision: run.revision.clone(),
+            });
+        }
+    }
+
+    if let Some((latest, earlier)) = runs.split_last() {
+        let mut baseline: Vec<Duration> =
+            earlier.iter().rev().filter(|run| run.comparable(latest)).take(window).map(|run| run.elapsed).collect();
+        if !baseline.is_empty() {
+            baseline.sort();
+            let median = baseline[baseline.len() / 2];
+            let percent = (latest.elapsed.as_secs_f64() / median.as_secs_f64().max(1e-9) - 1.0) * 100.0;
+            if percent > threshold {
+                alerts.push(Alert::Slower { percent, latest: latest.elapsed, baseline: median, runs: baseline.len() });
+            }
+        }
+    }
+    alerts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(revision: &str, millis: u64, answer: &str) -> RunRecord {
+        RunRecord {
+            timestamp: 1_700_000_000,
+            revision: revision.to_string(),
+            profile: "release".to_string(),
+            threads: 8,
+            year: 2024,
+            day: 6,
+            part: Part::Two,
+            elapsed: Duration::from_millis(millis),
+            input_hash: "00ff".to_string(),
+            answer: answer.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_history_round_trips() {
+        let path = env::temp_dir().join(format!("aoc-history-{}.txt", std::process::id()));
+        let _ = fs::remove_file(&path);
+        let records = [record("abc1234", 10, "1753"), record("abc1234-dirty", 12, "two words")];
+        History::append(&path, &records[..1]).unwrap();
+        History::append(&path, &records[1..]).unwrap();
+
+        let text = fs::read_to_string(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(text.lines().filter(|line| line.starts_with('#')).count(), 1);
+        assert_eq!(History::parse(&text).unwrap().records(), records);
+    }
+
+    #[test]
+    fn test_parse_rejects_short_lines() {
+        let err = History::parse("# header\n1 abc release 8 2024 6 2 100\n").unwrap_err();
+        assert!(matches!(err, HistoryError::Malformed { line: 2, .. }));
+    }
+
+    #[test]
+    fn test_the_year_is_required() {
+        let history = History::parse("1700000000 abc1234 release 8 2024 6 2 100 00ff two words\n").unwrap();
+        let mut expected = record("abc1234", 0, "two words");
+        expected.elapsed = Duration::from_nanos(100);
+        assert_eq!(history.records(), [expected]);
+        assert!(History::parse("1700000000 abc1234 release 8 6 2 100 00ff 42\n").is_err());
+        assert!(History::parse("1700000000 abc1234 release 8 x 6 2 100 00ff 42\n").is_err());
+    }
+
+    #[test]
+    fn test_slowdown_is_flagged_against_comparable_runs() {
+        let mut runs = [record("a", 100, "1"), record("b", 110, "1"), record("c", 90, "1"), record("d", 125, "1")];
+        let refs: Vec<&RunRecord> = runs.iter().collect();
+        let alerts = alerts(&refs, 5, 20.0);
+        assert_eq!(alerts.len(), 1);
+        assert!(matches!(alerts[0], Alert::Slower { runs: 3, .. }), "{:?}", alerts);
+
+        // A run with a different thread count is not a fair comparison
+        runs[3].threads = 1;
+        let refs: Vec<&RunRecord> = runs.iter().collect();
+        assert!(super::alerts(&refs, 5, 20.0).is_empty());
+    }
+
+    #[test]
+    fn test_changed_answer_is_flagged() {
+        let runs = [record("a", 100, "1753"), record("b", 100, "1754"), record("c", 100, "1754")];
+        let refs: Vec<&RunRecord> = runs.iter().collect();
+        let expected = Alert::AnswerChanged { from: "1753".into(), to: "1754".into(), revision: "b".into() };
+        assert_eq!(alerts(&refs, 5, 20.0), vec![expected]);
+        assert!(alerts(&refs, 1, 20.0).is_empty());
+    }
+}
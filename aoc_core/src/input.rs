@@ -0,0 +1,140 @@
+use std::env;
+use std::fmt;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+/// Environment variable that overrides the default inputs directory.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Where a day's puzzle input should be read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// A file given explicitly on the command line.
+    Path(PathBuf),
+    /// Standard input, read until EOF.
+    Stdin,
+    /// `<year>/day_<n>.txt` inside the inputs directory.
+    Default,
+}
+
+impl InputSource {
+    /// Interprets a command-line `--input` value: `-` means stdin, anything
+    /// else is a path.
+    pub fn from_arg(arg: &str) -> InputSource {
+        if arg == "-" {
+            InputSource::Stdin
+        } else {
+            InputSource::Path(PathBuf::from(arg))
+        }
+    }
+}
+
+impl InputSource {
+    /// Human-readable identifier of the input `day` of `year` is read from,
+    /// with the default resolved to its actual path.
+    pub fn describe(&self, year: u16, day: u8) -> String {
+        match self {
+            InputSource::Default => default_input_path(year, day).display().to_string(),
+            other => other.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputSource::Path(path) => write!(f, "{}", path.display()),
+            InputSource::Stdin => write!(f, "<stdin>"),
+            InputSource::Default => write!(f, "<default>"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    /// No input file exists for the day in the inputs directory, or only an
+    /// empty placeholder does.
+    NotFound { year: u16, day: u8, path: PathBuf },
+    /// The input exists but could not be read.
+    Io { source: String, error: io::Error },
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::NotFound { year, day, path } => write!(
+                f,
+                "no input found for day {} of {} (looked for {}); pass --input <path>, or --input - to read stdin",
+                day,
+                year,
+                path.display()
+            ),
+            InputError::Io { source, error } => write!(f, "failed to read input from {}: {}", source, error),
+        }
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            InputError::NotFound { .. } => None,
+            InputError::Io { error, .. } => Some(error),
+        }
+    }
+}
+
+/// Directory holding the inputs, one subdirectory per year:
+/// `$AOC_INPUT_DIR` if set, otherwise `inputs/` at the workspace root.
+pub fn input_dir() -> PathBuf {
+    match env::var_os(INPUT_DIR_VAR) {
+        Some(dir) => PathBuf::from(dir),
+        None => workspace_root().join("inputs"),
+    }
+}
+
+/// Root of the cargo workspace this crate was built in.
+pub fn workspace_root() -> PathBuf {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    manifest_dir.parent().unwrap_or(manifest_dir).to_path_buf()
+}
+
+/// Path of the input for `day` of `year` inside the inputs directory.
+pub fn default_input_path(year: u16, day: u8) -> PathBuf {
+    input_dir().join(year.to_string()).join(format!("day_{}.txt", day))
+}
+
+/// Reads the puzzle input for `day` of `year` from `source`.
+pub fn load_input(year: u16, day: u8, source: &InputSource) -> Result<String, InputError> {
+    match source {
+        InputSource::Path(path) => read_file(path),
+        InputSource::Stdin => {
+            let mut input = String::new();
+            io::stdin().read_to_string(&mut input).map_err(|error| InputError::Io {
+                source: source.to_string(),
+                error,
+            })?;
+            Ok(input)
+        }
+        InputSource::Default => {
+            let path = default_input_path(year, day);
+            if !path.is_file() {
+                return Err(InputError::NotFound { year, day, path });
+            }
+            // An empty file is the placeholder `aoc new` leaves for an input
+            // that has not been fetched yet
+            let input = read_file(&path)?;
+            if input.is_empty() {
+                return Err(InputError::NotFound { year, day, path });
+            }
+            Ok(input)
+        }
+    }
+}
+
+fn read_file(path: &Path) -> Result<String, InputError> {
+    fs::read_to_string(path).map_err(|error| InputError::Io {
+        source: path.display().to_string(),
+        error,
+    })
+}
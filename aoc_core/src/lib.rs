@@ -0,0 +1,30 @@
+//! Shared building blocks for the Advent of Code solvers.
+
+mod answer;
+mod answers;
+mod bench;
+mod budget;
+mod cancel;
+pub mod cli;
+mod error;
+mod history;
+mod input;
+mod parallel;
+mod progress;
+mod solution;
+
+pub use answer::Answer;
+pub use answers::{
+    check_answer, default_answers_path, input_hash, AnswerStore, AnswersError, KnownAnswer, RejectedAnswer, Verdict,
+};
+pub use bench::{bench, BenchError, BenchReport, Phase, Stats};
+pub use budget::{parse_duration, BudgetRule, TimeBudget};
+pub use cancel::{cancelled, current_cancel_token, with_cancel_token, CancelToken};
+pub use error::{parse_number, ParseError};
+pub use history::{alerts, default_history_path, Alert, History, HistoryError, RunRecord, HISTORY_VAR};
+pub use input::{default_input_path, input_dir, load_input, workspace_root, InputError, InputSource, INPUT_DIR_VAR};
+pub use parallel::{par_map, set_threads, threads};
+pub use progress::{progress_mode, set_progress_mode, Progress, ProgressMode};
+pub use solution::{
+    print_answers, solve_timed, solve_within, Parsed, Part, PartResult, Solution, Solver, TimedRun, FIRST_YEAR,
+};
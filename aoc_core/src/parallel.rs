@@ -0,0 +1,100 @@
+/// Applies `f` to every item of `items` and returns the results in the same
+/// order.
+///
+/// With the `parallel` feature the items are spread over the thread pool;
+/// without it they are processed one after the other. Either way the output
+/// order is that of `items`, so anything computed from it does not depend
+/// on the number of threads.
+///
+/// The caller's [`CancelToken`](crate::CancelToken) and tracing span are
+/// carried over to the worker threads, so `f` can poll
+/// [`cancelled`](crate::cancelled) as usual and its spans nest under the
+/// caller's.
+pub fn par_map<T, R, F>(items: &[T], f: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync + Send,
+{
+    #[cfg(feature = "parallel")]
+    {
+        use rayon::prelude::*;
+        let span = tracing::Span::current();
+        match crate::current_cancel_token() {
+            Some(token) => items
+                .par_iter()
+                .map(|item| span.in_scope(|| crate::with_cancel_token(&token, || f(item))))
+                .collect(),
+            None => items.par_iter().map(|item| span.in_scope(|| f(item))).collect(),
+        }
+    }
+    #[cfg(not(feature = "parallel"))]
+    {
+        items.iter().map(f).collect()
+    }
+}
+
+/// Sizes the thread pool used by [`par_map`]. Must be called before any
+/// parallel work starts, and at most once.
+///
+/// Without the `parallel` feature only a single thread is available.
+pub fn set_threads(threads: usize) -> Result<(), String> {
+    if threads == 0 {
+        return Err("the number of threads must be at least 1".to_string());
+    }
+
+    #[cfg(feature = "parallel")]
+    {
+        rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build_global()
+            .map_err(|err| format!("failed to set up {} threads: {}", threads, err))
+    }
+    #[cfg(not(feature = "parallel"))]
+    {
+        if threads == 1 {
+            Ok(())
+        } else {
+            Err(format!("cannot use {} threads: built without the 'parallel' feature", threads))
+        }
+    }
+}
+
+/// Number of threads [`par_map`] spreads work over.
+pub fn threads() -> usize {
+    #[cfg(feature = "parallel")]
+    {
+        rayon::current_num_threads()
+    }
+    #[cfg(not(feature = "parallel"))]
+    {
+        1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_par_map_keeps_input_order() {
+        let items: Vec<u64> = (0..1000).collect();
+        let squares = par_map(&items, |n| n * n);
+        assert_eq!(squares, items.iter().map(|n| n * n).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_par_map_sees_the_callers_token() {
+        let token = crate::CancelToken::new();
+        token.cancel();
+        let items: Vec<u64> = (0..100).collect();
+        let seen = crate::with_cancel_token(&token, || par_map(&items, |_| crate::cancelled()));
+        assert!(seen.into_iter().all(|cancelled| cancelled));
+        assert!(!par_map(&items, |_| crate::cancelled()).into_iter().any(|cancelled| cancelled));
+    }
+
+    #[test]
+    fn test_zero_threads_is_rejected() {
+        assert!(set_threads(0).is_err());
+    }
+}
@@ -0,0 +1,255 @@
+use std::fmt;
+use std::io::{self, IsTerminal, Write};
+use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, AtomicU8, AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::time::Instant;
+
+/// How long-running solvers report their progress on stderr.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProgressMode {
+    /// Report nothing.
+    Quiet,
+    /// A single bar redrawn in place; best on a terminal.
+    Bar,
+    /// A line every 10%; suits log files and CI output.
+    Log,
+}
+
+impl ProgressMode {
+    /// [`Bar`](ProgressMode::Bar) when stderr is a terminal, otherwise
+    /// [`Quiet`](ProgressMode::Quiet).
+    pub fn auto() -> ProgressMode {
+        if io::stderr().is_terminal() {
+            ProgressMode::Bar
+        } else {
+            ProgressMode::Quiet
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            ProgressMode::Quiet => "quiet",
+            ProgressMode::Bar => "bar",
+            ProgressMode::Log => "log",
+        }
+    }
+}
+
+impl fmt::Display for ProgressMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for ProgressMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<ProgressMode, String> {
+        match s {
+            "quiet" => Ok(ProgressMode::Quiet),
+            "bar" => Ok(ProgressMode::Bar),
+            "log" => Ok(ProgressMode::Log),
+            _ => Err(format!("unknown progress mode '{}' (expected quiet, bar or log)", s)),
+        }
+    }
+}
+
+static MODE: AtomicU8 = AtomicU8::new(0);
+
+/// Sets the mode used by every [`Progress`] created from now on. The
+/// default is [`ProgressMode::Quiet`].
+pub fn set_progress_mode(mode: ProgressMode) {
+    MODE.store(mode as u8, Ordering::Relaxed);
+}
+
+pub fn progress_mode() -> ProgressMode {
+    match MODE.load(Ordering::Relaxed) {
+        1 => ProgressMode::Bar,
+        2 => ProgressMode::Log,
+        _ => ProgressMode::Quiet,
+    }
+}
+
+const BAR_WIDTH: usize = 30;
+
+/// Progress of one long-running step, shared by the threads working on it.
+///
+/// Call [`inc`](Progress::inc) once per finished item; output is only
+/// written when the percentage changes, so this is cheap to call often.
+/// The report is closed off when the reporter is finished or dropped.
+pub struct Progress {
+    label: String,
+    total: usize,
+    mode: ProgressMode,
+    done: AtomicUsize,
+    shown: AtomicUsize,
+    finished: AtomicBool,
+    started: Instant,
+    // Also serialises drawing, so that reports from different threads
+    // never interleave
+    out: Mutex<Box<dyn Write + Send>>,
+}
+
+impl Progress {
+    /// A reporter for `total` items, in the current [`progress_mode`].
+    pub fn new(label: impl Into<String>, total: usize) -> Progress {
+        Progress::to_writer(label, total, progress_mode(), Box::new(io::stderr()))
+    }
+
+    fn to_writer(label: impl Into<String>, total: usize, mode: ProgressMode, out: Box<dyn Write + Send>) -> Progress {
+        Progress {
+            label: label.into(),
+            total,
+            mode,
+            done: AtomicUsize::new(0),
+            shown: AtomicUsize::new(0),
+            finished: AtomicBool::new(false),
+            started: Instant::now(),
+            out: Mutex::new(out),
+        }
+    }
+
+    /// Records one more finished item.
+    pub fn inc(&self) {
+        if self.mode == ProgressMode::Quiet || self.total == 0 {
+            return;
+        }
+        let done = self.done.fetch_add(1, Ordering::Relaxed) + 1;
+        let mut percent = done.min(self.total) * 100 / self.total;
+        if self.mode == ProgressMode::Log {
+            percent -= percent % 10;
+        }
+        if percent <= self.shown.load(Ordering::Relaxed) {
+            return;
+        }
+
+        let mut out = self.out.lock().unwrap();
+        // Another thread may have drawn a later percentage meanwhile
+        if percent <= self.shown.load(Ordering::Relaxed) || self.finished.load(Ordering::Relaxed) {
+            return;
+        }
+        self.shown.store(percent, Ordering::Relaxed);
+        match self.mode {
+            ProgressMode::Bar => self.draw_bar(&mut **out, percent, done),
+            ProgressMode::Log if percent < 100 => {
+                let _ = writeln!(out, "{}: {}% ({}/{})", self.label, percent, done, self.total);
+            }
+            _ => {}
+        }
+    }
+
+    /// Closes off the report: the bar is completed and a summary line is
+    /// logged. Further calls, and dropping the reporter, do nothing.
+    pub fn finish(&self) {
+        if self.mode == ProgressMode::Quiet || self.finished.swap(true, Ordering::Relaxed) {
+            return;
+        }
+        let done = self.done.load(Ordering::Relaxed);
+        let mut out = self.out.lock().unwrap();
+        match self.mode {
+            ProgressMode::Bar if self.total > 0 => {
+                let percent = done.min(self.total) * 100 / self.total;
+                self.draw_bar(&mut **out, percent, done);
+                let _ = writeln!(out);
+            }
+            ProgressMode::Log => {
+                let _ = writeln!(out, "{}: done {}/{} in {:.2?}", self.label, done, self.total, self.started.elapsed());
+            }
+            _ => {}
+        }
+        let _ = out.flush();
+    }
+
+    fn draw_bar(&self, out: &mut dyn Write, percent: usize, done: usize) {
+        let filled = percent * BAR_WIDTH / 100;
+        let _ = write!(
+            out,
+            "\r{} [{}{}] {:3}% ({}/{})",
+            self.label,
+            "#".repeat(filled),
+            " ".repeat(BAR_WIDTH - filled),
+            percent,
+            done,
+            self.total
+        );
+        let _ = out.flush();
+    }
+}
+
+impl Drop for Progress {
+    fn drop(&mut self) {
+        self.finish();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    #[derive(Clone, Default)]
+    struct Captured(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for Captured {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl Captured {
+        fn text(&self) -> String {
+            String::from_utf8(self.0.lock().unwrap().clone()).unwrap()
+        }
+    }
+
+    fn run(mode: ProgressMode, total: usize) -> String {
+        let captured = Captured::default();
+        let progress = Progress::to_writer("test", total, mode, Box::new(captured.clone()));
+        for _ in 0..total {
+            progress.inc();
+        }
+        drop(progress);
+        captured.text()
+    }
+
+    #[test]
+    fn test_quiet_writes_nothing() {
+        assert_eq!(run(ProgressMode::Quiet, 50), "");
+    }
+
+    #[test]
+    fn test_log_writes_a_line_every_ten_percent() {
+        let text = run(ProgressMode::Log, 200);
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(lines.len(), 10);
+        assert_eq!(lines[0], "test: 10% (20/200)");
+        assert!(lines[9].starts_with("test: done 200/200 in "));
+    }
+
+    #[test]
+    fn test_log_copes_with_few_items() {
+        let text = run(ProgressMode::Log, 3);
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(lines[..2], ["test: 30% (1/3)", "test: 60% (2/3)"]);
+        assert_eq!(lines.len(), 3);
+    }
+
+    #[test]
+    fn test_bar_redraws_in_place_and_ends_the_line() {
+        let text = run(ProgressMode::Bar, 4);
+        assert_eq!(text.matches('\r').count(), 5);
+        assert!(text.ends_with(&format!("\rtest [{}] 100% (4/4)\n", "#".repeat(BAR_WIDTH))));
+    }
+
+    #[test]
+    fn test_parse_mode() {
+        assert_eq!("log".parse(), Ok(ProgressMode::Log));
+        assert!("loud".parse::<ProgressMode>().is_err());
+    }
+}
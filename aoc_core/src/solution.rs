@@ -0,0 +1,208 @@
+use crate::{with_cancel_token, Answer, CancelToken, ParseError, TimeBudget};
+use std::any::Any;
+use std::fmt;
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// One of the two parts every puzzle is split into.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    /// Returns the part for `1` or `2`.
+    pub fn from_number(n: u8) -> Option<Part> {
+        match n {
+            1 => Some(Part::One),
+            2 => Some(Part::Two),
+            _ => None,
+        }
+    }
+
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+/// The year this workspace started out with, back when it held no other.
+pub const FIRST_YEAR: u16 = 2024;
+
+/// A day's puzzle: how to parse its input and how to solve both parts.
+pub trait Solution {
+    /// Year of the event the puzzle is from.
+    const YEAR: u16;
+    /// Day of December the puzzle was released.
+    const DAY: u8;
+
+    /// The parsed puzzle input shared by both parts.
+    type Input;
+
+    /// Parses the raw puzzle input.
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    /// Solves part one of the puzzle.
+    fn part_one(input: &Self::Input) -> Answer;
+    /// Solves part two, which is only unlocked after part one.
+    fn part_two(input: &Self::Input) -> Answer;
+}
+
+/// A day's parsed input with its concrete type erased, as produced by
+/// [`Solver::parse`].
+pub struct Parsed(Box<dyn Any + Send + Sync>);
+
+/// Object-safe view of a [`Solution`], so different days can be driven
+/// through the same `&dyn Solver`.
+pub trait Solver: Sync {
+    /// Year of the event the puzzle is from.
+    fn year(&self) -> u16;
+
+    /// Day of December the puzzle was released.
+    fn day(&self) -> u8;
+
+    /// Parses the raw puzzle input.
+    fn parse(&self, input: &str) -> Result<Parsed, ParseError>;
+
+    /// Solves `part` on input previously returned by [`Solver::parse`] on
+    /// the same solver.
+    fn solve_parsed(&self, parsed: &Parsed, part: Part) -> Answer;
+
+    /// Parses `input` once and solves each of `parts` in order.
+    fn solve(&self, input: &str, parts: &[Part]) -> Result<Vec<(Part, Answer)>, ParseError> {
+        let parsed = self.parse(input)?;
+        Ok(parts.iter().map(|&part| (part, self.solve_parsed(&parsed, part))).collect())
+    }
+}
+
+impl<S> Solver for S
+where
+    S: Solution + Sync,
+    S::Input: Send + Sync + 'static,
+{
+    fn year(&self) -> u16 {
+        S::YEAR
+    }
+
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn parse(&self, input: &str) -> Result<Parsed, ParseError> {
+        let _span = tracing::info_span!("parse", year = S::YEAR, day = S::DAY).entered();
+        Ok(Parsed(Box::new(S::parse(input)?)))
+    }
+
+    fn solve_parsed(&self, parsed: &Parsed, part: Part) -> Answer {
+        let _span = match part {
+            Part::One => tracing::info_span!("part_one", year = S::YEAR, day = S::DAY),
+            Part::Two => tracing::info_span!("part_two", year = S::YEAR, day = S::DAY),
+        }
+        .entered();
+        let input = parsed
+            .0
+            .downcast_ref::<S::Input>()
+            .expect("parsed input belongs to a different day");
+        match part {
+            Part::One => S::part_one(input),
+            Part::Two => S::part_two(input),
+        }
+    }
+}
+
+/// Solves `part` like [`Solver::solve_parsed`], but gives up once `limit`
+/// has passed.
+///
+/// The solver runs under a fresh [`CancelToken`] that is cancelled when
+/// time runs out; solvers that can take long poll
+/// [`cancelled`](crate::cancelled) and return early. Returns `None` if the
+/// part ran out of time, whatever it returned.
+pub fn solve_within(solver: &dyn Solver, parsed: &Parsed, part: Part, limit: Option<Duration>) -> Option<Answer> {
+    let Some(limit) = limit else {
+        return Some(solver.solve_parsed(parsed, part));
+    };
+
+    let token = CancelToken::new();
+    let (done, finished) = mpsc::channel::<()>();
+    let watchdog = {
+        let token = token.clone();
+        thread::spawn(move || {
+            if finished.recv_timeout(limit) == Err(RecvTimeoutError::Timeout) {
+                token.cancel();
+            }
+        })
+    };
+
+    let answer = with_cancel_token(&token, || solver.solve_parsed(parsed, part));
+    // Hanging up wakes the watchdog straight away
+    drop(done);
+    watchdog.join().expect("watchdog thread panicked");
+    (!token.is_cancelled()).then_some(answer)
+}
+
+/// The answer to one part, with how long solving it took.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartResult {
+    pub part: Part,
+    /// `None` when the part ran out of its time budget.
+    pub answer: Option<Answer>,
+    pub elapsed: Duration,
+}
+
+/// Answers of a timed run, see [`solve_timed`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimedRun {
+    pub parse_time: Duration,
+    pub results: Vec<PartResult>,
+}
+
+/// Like [`Solver::solve`], but also measures parsing and each part, and
+/// gives each part at most the time `budget` allows it.
+pub fn solve_timed(solver: &dyn Solver, input: &str, parts: &[Part], budget: &TimeBudget) -> Result<TimedRun, ParseError> {
+    let start = Instant::now();
+    let parsed = solver.parse(input)?;
+    let parse_time = start.elapsed();
+
+    let results = parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let answer = solve_within(solver, &parsed, part, budget.limit(solver.year(), solver.day(), part));
+            PartResult { part, answer, elapsed: start.elapsed() }
+        })
+        .collect();
+    Ok(TimedRun { parse_time, results })
+}
+
+/// Solves `part` (or both parts when `None`) within `budget` and prints one
+/// line per answer.
+///
+/// Returns the number of parts that ran out of time.
+pub fn print_answers(solver: &dyn Solver, input: &str, part: Option<Part>, budget: &TimeBudget) -> Result<usize, ParseError> {
+    let parts = match part {
+        Some(part) => vec![part],
+        None => Part::ALL.to_vec(),
+    };
+    let mut timed_out = 0;
+    for result in solve_timed(solver, input, &parts, budget)?.results {
+        match result.answer {
+            Some(answer) => println!("Day {} part {}: {}", solver.day(), result.part, answer),
+            None => {
+                println!("Day {} part {}: timed out after {:.2?}", solver.day(), result.part, result.elapsed);
+                timed_out += 1;
+            }
+        }
+    }
+    Ok(timed_out)
+}
@@ -0,0 +1,173 @@
+//! Differential testing: runs the real solvers and naive reference
+//! solutions on generated inputs and reports the first disagreement, with
+//! the input shrunk to something small enough to debug by hand.
+
+mod minimise;
+mod reference;
+
+pub use minimise::minimise;
+pub use reference::{reference, Reference};
+
+use aoc_core::{Answer, Part, Solver};
+use std::fmt;
+
+/// A part on which a solver and its reference solution disagree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mismatch {
+    pub part: Part,
+    /// The reference solution's answer.
+    pub expected: Answer,
+    /// The solver's answer, or its parse error.
+    pub actual: Result<Answer, String>,
+}
+
+/// The first disagreement found for a day, with the generated input that
+/// showed it and a minimised version of that input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Divergence {
+    pub day: u8,
+    pub seed: u64,
+    pub size: usize,
+    pub input: String,
+    pub minimised: String,
+    pub mismatch: Mismatch,
+}
+
+impl fmt::Display for Divergence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let actual = match &self.mismatch.actual {
+            Ok(answer) => answer.to_string(),
+            Err(message) => format!("parse error: {}", message),
+        };
+        writeln!(
+            f,
+            "day {} part {} diverges on seed {} size {}: expected {}, got {}",
+            self.day, self.mismatch.part, self.seed, self.size, self.mismatch.expected, actual
+        )?;
+        writeln!(f, "minimised input ({} bytes, from {}):", self.minimised.len(), self.input.len())?;
+        write!(f, "{}", self.minimised)
+    }
+}
+
+/// Runs `solver` on `input` and compares it with `reference`. Returns `None`
+/// when they agree on every part, or when the input is outside what the
+/// puzzle promises and so has no reference answer.
+pub fn compare(solver: &dyn Solver, reference: Reference, input: &str) -> Option<Mismatch> {
+    let expected = reference(input)?;
+    let parsed = match solver.parse(input) {
+        Ok(parsed) => parsed,
+        Err(err) => {
+            return Some(Mismatch { part: Part::One, expected: expected[0].clone(), actual: Err(err.to_string()) })
+        }
+    };
+
+    Part::ALL.into_iter().zip(expected).find_map(|(part, expected)| {
+        let actual = solver.solve_parsed(&parsed, part);
+        (actual != expected).then_some(Mismatch { part, expected, actual: Ok(actual) })
+    })
+}
+
+/// Checks `solver` against its day's reference on the inputs generated
+/// from each of `seeds` at `size`, stopping at the first divergence.
+///
+/// Returns the number of inputs checked. Panics if the day has no
+/// generator or no reference solution, as for any year but
+/// [`aoc_gen::YEAR`].
+pub fn check(solver: &dyn Solver, seeds: impl IntoIterator<Item = u64>, size: usize) -> Result<usize, Box<Divergence>> {
+    let (year, day) = (solver.year(), solver.day());
+    let reference = reference(day)
+        .filter(|_| year == aoc_gen::YEAR)
+        .unwrap_or_else(|| panic!("day {} of {} has no reference solution", day, year));
+    let mut checked = 0;
+
+    for seed in seeds {
+        let input = aoc_gen::generate(day, seed, size).unwrap_or_else(|| panic!("day {} has no generator", day));
+        if let Some(mismatch) = compare(solver, reference, &input) {
+            let minimised = minimise(&input, |candidate| {
+                compare(solver, reference, candidate).is_some_and(|m| m.part == mismatch.part)
+            });
+            let mismatch = compare(solver, reference, &minimised).expect("minimised input still diverges");
+            return Err(Box::new(Divergence { day, seed, size, input, minimised, mismatch }));
+        }
+        checked += 1;
+    }
+    Ok(checked)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::{ParseError, Solution};
+
+    fn assert_agrees(solver: &dyn Solver, size: usize) {
+        if let Err(divergence) = check(solver, 0..40, size) {
+            panic!("{}", divergence);
+        }
+    }
+
+    #[test]
+    fn test_day_1() {
+        assert_agrees(&day_1::Day1, 30);
+    }
+
+    #[test]
+    fn test_day_2() {
+        assert_agrees(&day_2::Day2, 30);
+    }
+
+    #[test]
+    fn test_day_3() {
+        assert_agrees(&day_3::Day3, 10);
+    }
+
+    #[test]
+    fn test_day_4() {
+        assert_agrees(&day_4::Day4, 12);
+    }
+
+    #[test]
+    fn test_day_5() {
+        assert_agrees(&day_5::Day5, 20);
+    }
+
+    #[test]
+    fn test_day_6() {
+        assert_agrees(&day_6::Day6, 10);
+    }
+
+    #[test]
+    fn test_day_7() {
+        assert_agrees(&day_7::Day7, 20);
+    }
+
+    /// Day 1 with part one off by one whenever some left ID is odd.
+    struct BuggyDay1;
+
+    impl Solution for BuggyDay1 {
+        const YEAR: u16 = 2024;
+        const DAY: u8 = 1;
+        type Input = day_1::LocationLists;
+
+        fn parse(input: &str) -> Result<Self::Input, ParseError> {
+            <day_1::Day1 as Solution>::parse(input)
+        }
+
+        fn part_one(lists: &Self::Input) -> Answer {
+            let bug = lists.left.iter().any(|id| id % 2 == 1);
+            (day_1::part_one(lists) + i32::from(bug)).into()
+        }
+
+        fn part_two(lists: &Self::Input) -> Answer {
+            day_1::part_two(lists).into()
+        }
+    }
+
+    #[test]
+    fn test_divergence_is_reported_minimised() {
+        let divergence = check(&BuggyDay1, 0..10, 50).unwrap_err();
+        assert_eq!(divergence.seed, 0);
+        assert_eq!(divergence.mismatch.part, Part::One);
+        assert_eq!(divergence.minimised.lines().count(), 1);
+        assert!(divergence.minimised.len() < divergence.input.len());
+    }
+}
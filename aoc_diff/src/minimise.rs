@@ -0,0 +1,75 @@
+/// Shrinks `input` as far as possible while `interesting` keeps returning
+/// `true`, first by dropping whole lines, then characters within lines.
+///
+/// `interesting(input)` must hold to begin with. The result always ends in
+/// a newline.
+pub fn minimise(input: &str, mut interesting: impl FnMut(&str) -> bool) -> String {
+    let mut lines: Vec<String> = input.lines().map(String::from).collect();
+    lines = shrink(lines, |lines| interesting(&join(lines)));
+
+    for i in 0..lines.len() {
+        let mut others = lines.clone();
+        let chars: Vec<char> = lines[i].chars().collect();
+        let chars = shrink(chars, |chars| {
+            others[i] = chars.iter().collect();
+            interesting(&join(&others))
+        });
+        lines[i] = chars.into_iter().collect();
+    }
+
+    join(&lines)
+}
+
+fn join(lines: &[String]) -> String {
+    lines.iter().map(|line| format!("{}\n", line)).collect()
+}
+
+/// Removes ever smaller runs of `items` for as long as `keep` still holds
+/// without them, finishing with single items until none can go.
+fn shrink<T: Clone>(mut items: Vec<T>, mut keep: impl FnMut(&[T]) -> bool) -> Vec<T> {
+    let mut run = (items.len() / 2).max(1);
+    loop {
+        let mut removed = false;
+        let mut start = 0;
+        while start < items.len() {
+            let end = (start + run).min(items.len());
+            let candidate: Vec<T> = items[..start].iter().chain(&items[end..]).cloned().collect();
+            if keep(&candidate) {
+                items = candidate;
+                removed = true;
+            } else {
+                start += run;
+            }
+        }
+
+        if run > 1 {
+            run /= 2;
+        } else if !removed {
+            return items;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_keeps_only_the_lines_that_matter() {
+        let input = "a\nb\nbad\nc\nd\n";
+        assert_eq!(minimise(input, |s| s.contains("bad")), "bad\n");
+    }
+
+    #[test]
+    fn test_shrinks_within_lines() {
+        let input = "x1\nabcXdef\nx2\n";
+        assert_eq!(minimise(input, |s| s.contains('X')), "X\n");
+    }
+
+    #[test]
+    fn test_keeps_lines_that_depend_on_each_other() {
+        let input = "1\n2\n3\n4\n5\n6\n";
+        let minimised = minimise(input, |s| s.contains('2') && s.contains('5'));
+        assert_eq!(minimised, "2\n5\n");
+    }
+}
@@ -0,0 +1,276 @@
+//! Deliberately naive solutions, written straight from the puzzle text with
+//! no attempt at speed, to check the real solvers against.
+//!
+//! Each takes the raw input and returns both answers, or `None` when the
+//! input breaks a promise the puzzle makes (such as the guard eventually
+//! leaving the map), where the real solvers are allowed to do anything.
+
+use aoc_core::Answer;
+use std::collections::HashSet;
+
+/// A reference solution for one day.
+pub type Reference = fn(&str) -> Option<[Answer; 2]>;
+
+/// The reference solution for `day` of [`aoc_gen::YEAR`], if there is one.
+pub fn reference(day: u8) -> Option<Reference> {
+    let reference: Reference = match day {
+        1 => day_1,
+        2 => day_2,
+        3 => day_3,
+        4 => day_4,
+        5 => day_5,
+        6 => day_6,
+        7 => day_7,
+        _ => return None,
+    };
+    Some(reference)
+}
+
+fn numbers(line: &str, separator: char) -> Option<Vec<i64>> {
+    line.split(separator).filter(|s| !s.is_empty()).map(|s| s.parse().ok()).collect()
+}
+
+fn non_empty_lines(input: &str) -> impl Iterator<Item = &str> {
+    input.lines().filter(|line| !line.trim().is_empty())
+}
+
+fn day_1(input: &str) -> Option<[Answer; 2]> {
+    let mut left = Vec::new();
+    let mut right = Vec::new();
+    for line in non_empty_lines(input) {
+        let [l, r] = numbers(line, ' ')?[..] else { return None };
+        left.push(l);
+        right.push(r);
+    }
+    left.sort();
+    right.sort();
+
+    let distance: i64 = left.iter().zip(&right).map(|(l, r)| (l - r).abs()).sum();
+    let similarity: i64 = left.iter().map(|l| l * right.iter().filter(|&r| r == l).count() as i64).sum();
+    Some([distance.into(), similarity.into()])
+}
+
+fn day_2(input: &str) -> Option<[Answer; 2]> {
+    fn is_safe(levels: &[i64]) -> bool {
+        let diffs: Vec<i64> = levels.windows(2).map(|w| w[1] - w[0]).collect();
+        diffs.iter().all(|d| (1..=3).contains(d)) || diffs.iter().all(|d| (-3..=-1).contains(d))
+    }
+
+    let reports: Vec<Vec<i64>> = non_empty_lines(input).map(|line| numbers(line, ' ')).collect::<Option<_>>()?;
+    let safe = reports.iter().filter(|r| is_safe(r)).count();
+    let dampened = reports
+        .iter()
+        .filter(|r| {
+            is_safe(r)
+                || (0..r.len()).any(|skip| {
+                    let shortened: Vec<i64> =
+                        r.iter().enumerate().filter(|&(i, _)| i != skip).map(|(_, &level)| level).collect();
+                    is_safe(&shortened)
+                })
+        })
+        .count();
+    Some([safe.into(), dampened.into()])
+}
+
+fn day_3(input: &str) -> Option<[Answer; 2]> {
+    /// Reads a 1-3 digit number from the start of `s`, returning it and the
+    /// rest of `s`.
+    fn number(s: &str) -> Option<(i64, &str)> {
+        let len = s.bytes().take_while(u8::is_ascii_digit).count();
+        if !(1..=3).contains(&len) {
+            return None;
+        }
+        Some((s[..len].parse().ok()?, &s[len..]))
+    }
+
+    fn mul(s: &str) -> Option<i64> {
+        let s = s.strip_prefix("mul(")?;
+        let (x, s) = number(s)?;
+        let s = s.strip_prefix(',')?;
+        let (y, s) = number(s)?;
+        s.strip_prefix(')')?;
+        Some(x * y)
+    }
+
+    let mut all = 0;
+    let mut enabled_only = 0;
+    let mut enabled = true;
+    for (i, _) in input.char_indices() {
+        let rest = &input[i..];
+        if rest.starts_with("do()") {
+            enabled = true;
+        } else if rest.starts_with("don't()") {
+            enabled = false;
+        } else if let Some(product) = mul(rest) {
+            all += product;
+            if enabled {
+                enabled_only += product;
+            }
+        }
+    }
+    Some([all.into(), enabled_only.into()])
+}
+
+fn grid(input: &str) -> Option<Vec<Vec<char>>> {
+    let grid: Vec<Vec<char>> = non_empty_lines(input).map(|line| line.chars().collect()).collect();
+    let width = grid.first().map_or(0, Vec::len);
+    grid.iter().all(|row| row.len() == width).then_some(grid)
+}
+
+fn day_4(input: &str) -> Option<[Answer; 2]> {
+    let grid = grid(input)?;
+    let at = |row: i64, col: i64| {
+        let row = grid.get(usize::try_from(row).ok()?)?;
+        row.get(usize::try_from(col).ok()?).copied()
+    };
+
+    let mut xmas = 0;
+    let mut x_mas = 0;
+    for row in 0..grid.len() as i64 {
+        for col in 0..grid[0].len() as i64 {
+            for dr in -1..=1 {
+                for dc in -1..=1 {
+                    let word: String = (0..4).filter_map(|i| at(row + dr * i, col + dc * i)).collect();
+                    if (dr, dc) != (0, 0) && word == "XMAS" {
+                        xmas += 1;
+                    }
+                }
+            }
+
+            let diagonal = |dr: i64| [at(row - 1, col - dr), at(row + 1, col + dr)];
+            let is_mas = |ends: [Option<char>; 2]| ends == [Some('M'), Some('S')] || ends == [Some('S'), Some('M')];
+            if at(row, col) == Some('A') && is_mas(diagonal(1)) && is_mas(diagonal(-1)) {
+                x_mas += 1;
+            }
+        }
+    }
+    Some([xmas.into(), x_mas.into()])
+}
+
+fn day_5(input: &str) -> Option<[Answer; 2]> {
+    let (rules, updates) = input.split_once("\n\n")?;
+    let rules: HashSet<(i64, i64)> = non_empty_lines(rules)
+        .map(|line| match numbers(line, '|')?[..] {
+            [before, after] => Some((before, after)),
+            _ => None,
+        })
+        .collect::<Option<_>>()?;
+    let updates: Vec<Vec<i64>> = non_empty_lines(updates).map(|line| numbers(line, ',')).collect::<Option<_>>()?;
+
+    let mut ordered = 0;
+    let mut reordered = 0;
+    for update in updates {
+        // The puzzle only ever asks to order pages that have a rule between
+        // them; anything else has no single right answer
+        for (i, &a) in update.iter().enumerate() {
+            for &b in &update[i + 1..] {
+                if !rules.contains(&(a, b)) && !rules.contains(&(b, a)) {
+                    return None;
+                }
+            }
+        }
+
+        let in_order = update.iter().enumerate().all(|(i, &a)| update[i + 1..].iter().all(|&b| !rules.contains(&(b, a))));
+        if in_order {
+            ordered += update[update.len() / 2];
+        } else {
+            // Repeatedly take the page no remaining page must precede
+            let mut remaining = update.clone();
+            let mut sorted = Vec::new();
+            while !remaining.is_empty() {
+                let next = remaining.iter().position(|&a| remaining.iter().all(|&b| !rules.contains(&(b, a))))?;
+                sorted.push(remaining.remove(next));
+            }
+            reordered += sorted[sorted.len() / 2];
+        }
+    }
+    Some([ordered.into(), reordered.into()])
+}
+
+fn day_6(input: &str) -> Option<[Answer; 2]> {
+    /// The cells the guard visits before leaving, or `None` if they never
+    /// leave.
+    fn walk(grid: &[Vec<char>], start: (i64, i64)) -> Option<HashSet<(i64, i64)>> {
+        const DELTAS: [(i64, i64); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+        let (height, width) = (grid.len() as i64, grid[0].len() as i64);
+        let (mut pos, mut dir) = (start, 0);
+        let mut states = HashSet::new();
+
+        while states.insert((pos, dir)) {
+            let next = (pos.0 + DELTAS[dir].0, pos.1 + DELTAS[dir].1);
+            if next.0 < 0 || next.0 >= height || next.1 < 0 || next.1 >= width {
+                return Some(states.into_iter().map(|(pos, _)| pos).collect());
+            }
+            if grid[next.0 as usize][next.1 as usize] == '#' {
+                dir = (dir + 1) % 4;
+            } else {
+                pos = next;
+            }
+        }
+        None
+    }
+
+    let mut grid = grid(input)?;
+    let guards: Vec<(i64, i64)> = (0..grid.len())
+        .flat_map(|row| (0..grid[row].len()).map(move |col| (row, col)))
+        .filter(|&(row, col)| grid[row][col] == '^')
+        .map(|(row, col)| (row as i64, col as i64))
+        .collect();
+    let [start] = guards[..] else { return None };
+    if grid.iter().flatten().any(|c| !".#^".contains(*c)) {
+        return None;
+    }
+
+    let visited = walk(&grid, start)?.len();
+    let mut loops = 0;
+    for row in 0..grid.len() {
+        for col in 0..grid[row].len() {
+            if grid[row][col] == '.' {
+                grid[row][col] = '#';
+                if walk(&grid, start).is_none() {
+                    loops += 1;
+                }
+                grid[row][col] = '.';
+            }
+        }
+    }
+    Some([visited.into(), loops.into()])
+}
+
+fn day_7(input: &str) -> Option<[Answer; 2]> {
+    /// Whether some choice of `operators` operators makes `operands` equal
+    /// `target`, trying every combination in turn.
+    fn solvable(target: i128, operands: &[i128], operators: u32) -> bool {
+        let gaps = operands.len() as u32 - 1;
+        (0..operators.pow(gaps)).any(|mut combination| {
+            let mut value = operands[0];
+            for &operand in &operands[1..] {
+                value = match combination % operators {
+                    0 => value + operand,
+                    1 => value * operand,
+                    _ => format!("{}{}", value, operand).parse().unwrap(),
+                };
+                combination /= operators;
+            }
+            value == target
+        })
+    }
+
+    let mut with_two = 0;
+    let mut with_three = 0;
+    for line in non_empty_lines(input) {
+        let (target, operands) = line.split_once(':')?;
+        let target: i128 = target.trim().parse().ok()?;
+        let operands: Vec<i128> = operands.split_whitespace().map(|s| s.parse().ok()).collect::<Option<_>>()?;
+        if operands.is_empty() {
+            return None;
+        }
+        if solvable(target, &operands, 2) {
+            with_two += target;
+        }
+        if solvable(target, &operands, 3) {
+            with_three += target;
+        }
+    }
+    Some([i64::try_from(with_two).ok()?.into(), i64::try_from(with_three).ok()?.into()])
+}
@@ -0,0 +1,19 @@
+//! Location-ID lists: `size` lines of two five-digit IDs.
+
+use crate::Rng;
+
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut left = Vec::with_capacity(size);
+    let mut out = String::new();
+
+    for _ in 0..size {
+        let id = rng.range(10_000, 99_999);
+        left.push(id);
+        // Reuse IDs from the left list now and then so that part two has
+        // something to count
+        let other = if rng.chance(30) { *rng.pick(&left) } else { rng.range(10_000, 99_999) };
+        out.push_str(&format!("{}   {}\n", id, other));
+    }
+
+    out
+}
@@ -0,0 +1,28 @@
+//! Reports: `size` lines of five to eight levels that mostly change
+//! gradually, with the occasional bad step.
+
+use crate::Rng;
+
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut out = String::new();
+
+    for _ in 0..size {
+        let len = rng.range(5, 8);
+        let direction = if rng.chance(50) { 1 } else { -1 };
+        // Steps are at most 5 either way, so levels stay positive
+        let mut level = rng.range(41, 59);
+        let mut levels = vec![level];
+
+        for _ in 1..len {
+            let step = if rng.chance(85) { direction * rng.range(1, 3) } else { rng.range(-5, 5) };
+            level += step;
+            levels.push(level);
+        }
+
+        let line: Vec<String> = levels.iter().map(i64::to_string).collect();
+        out.push_str(&line.join(" "));
+        out.push('\n');
+    }
+
+    out
+}
@@ -0,0 +1,37 @@
+//! Corrupted memory: `size` lines mixing `mul(a,b)`, `do()` and `don't()`
+//! instructions with junk and almost-instructions.
+
+use crate::Rng;
+
+const JUNK: &[char] = &[
+    '!', '@', '#', '$', '%', '^', '&', '*', '(', ')', '[', ']', '{', '}', '<', '>', '+', '-', '_', '=', '?', '/', ',',
+    ':', ';', '\'', ' ', 'm', 'u', 'l', 'd', 'o', 'n', 't', 'x', 'y', 'w', 'h', 'e', 'r', 'f', 'i', 's', '0', '7',
+];
+
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut out = String::new();
+
+    for _ in 0..size {
+        for _ in 0..rng.range(8, 16) {
+            let a = rng.range(1, 999);
+            let b = rng.range(1, 999);
+            let fragment = match rng.below(10) {
+                0..=2 => format!("mul({},{})", a, b),
+                3 => "do()".to_string(),
+                4 => "don't()".to_string(),
+                5 => match rng.below(5) {
+                    0 => format!("mul({},{}]", a, b),
+                    1 => format!("mul[{},{}]", a, b),
+                    2 => format!("mul ( {} , {} )", a, b),
+                    3 => format!("mul({}*", a),
+                    _ => format!("mul({},{})", a * 10 + 9, b),
+                },
+                _ => (0..rng.range(1, 6)).map(|_| *rng.pick(JUNK)).collect(),
+            };
+            out.push_str(&fragment);
+        }
+        out.push('\n');
+    }
+
+    out
+}
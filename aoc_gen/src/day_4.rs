@@ -0,0 +1,14 @@
+//! Word searches: a `size` by `size` grid of the letters X, M, A and S.
+
+use crate::Rng;
+
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut out = String::new();
+
+    for _ in 0..size.max(1) {
+        out.extend((0..size.max(1)).map(|_| *rng.pick(&['X', 'M', 'A', 'S'])));
+        out.push('\n');
+    }
+
+    out
+}
@@ -0,0 +1,37 @@
+//! Print queues: a complete set of ordering rules over up to 49 pages,
+//! then `size` updates of an odd number of pages, about half of them in
+//! the right order.
+
+use crate::Rng;
+
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut order: Vec<i64> = (10..=99).collect();
+    rng.shuffle(&mut order);
+    order.truncate((size / 2).clamp(3, 49));
+
+    // Every pair of pages gets a rule, as in the real puzzle, so that each
+    // update has exactly one correct order
+    let mut rules = Vec::new();
+    for (i, before) in order.iter().enumerate() {
+        for after in &order[i + 1..] {
+            rules.push(format!("{}|{}", before, after));
+        }
+    }
+    rng.shuffle(&mut rules);
+
+    let max_len = order.len().min(23) as i64;
+    let mut updates = Vec::new();
+    for _ in 0..size.max(1) {
+        let len = (rng.range(1, max_len) | 1).min(max_len) as usize;
+        let mut pages = order.clone();
+        rng.shuffle(&mut pages);
+        pages.truncate(len);
+        if rng.chance(50) {
+            pages.sort_by_key(|page| order.iter().position(|p| p == page));
+        }
+        let pages: Vec<String> = pages.iter().map(i64::to_string).collect();
+        updates.push(pages.join(","));
+    }
+
+    format!("{}\n\n{}\n", rules.join("\n"), updates.join("\n"))
+}
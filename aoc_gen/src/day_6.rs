@@ -0,0 +1,25 @@
+//! Guard maps: a `size` by `size` map with scattered obstacles and one
+//! guard who eventually walks off the map, as the puzzle promises.
+
+use crate::Rng;
+
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let side = size.max(1);
+
+    for attempt in 0.. {
+        // Fall back to an empty map, which every guard leaves, rather than
+        // retry forever on unlucky seeds
+        let density = if attempt < 100 { 12 } else { 0 };
+        let mut grid: Vec<Vec<char>> = (0..side)
+            .map(|_| (0..side).map(|_| if rng.chance(density) { '#' } else { '.' }).collect())
+            .collect();
+        let guard = (rng.below(side as u64) as usize, rng.below(side as u64) as usize);
+        grid[guard.0][guard.1] = '^';
+
+        let text: String = grid.iter().map(|row| row.iter().collect::<String>() + "\n").collect();
+        if day_6::Map::from_input(&text).is_ok_and(|map| map.guard_leaves()) {
+            return text;
+        }
+    }
+    unreachable!()
+}
@@ -0,0 +1,39 @@
+//! Calibration equations: `size` lines of one to seven two-digit operands,
+//! about half of them with a test value some mix of `+`, `*` and `||`
+//! actually produces, and some with the value of only their last few
+//! operands.
+
+use crate::Rng;
+
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut out = String::new();
+
+    for _ in 0..size {
+        // Operands stay below 100 so that even all-concatenated values fit
+        // comfortably in an i64
+        let operands: Vec<i64> = (0..rng.range(1, 7)).map(|_| rng.range(1, 99)).collect();
+        // Sometimes the test value comes from the later operands only, which
+        // a solver that can drop the leading ones would wrongly accept
+        let start = if operands.len() > 1 && rng.chance(20) {
+            rng.range(1, operands.len() as i64 - 1) as usize
+        } else {
+            0
+        };
+        let mut value = operands[start];
+        for &operand in &operands[start + 1..] {
+            value = match rng.below(3) {
+                0 => value + operand,
+                1 => value * operand,
+                _ => format!("{}{}", value, operand).parse().unwrap(),
+            };
+        }
+        if rng.chance(50) {
+            value += rng.range(1, 50);
+        }
+
+        let operands: Vec<String> = operands.iter().map(i64::to_string).collect();
+        out.push_str(&format!("{}: {}\n", value, operands.join(" ")));
+    }
+
+    out
+}
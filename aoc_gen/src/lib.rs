@@ -0,0 +1,102 @@
+//! Seeded generators of random puzzle inputs, for stress-testing solvers on
+//! inputs bigger and stranger than a personal puzzle input.
+//!
+//! Every generator writes text in the exact format its day's parser
+//! accepts, and the same seed and size always give the same text.
+
+mod day_1;
+mod day_2;
+mod day_3;
+mod day_4;
+mod day_5;
+mod day_6;
+mod day_7;
+mod rng;
+
+pub use rng::Rng;
+
+/// Year of the puzzles the generators write inputs for.
+pub const YEAR: u16 = 2024;
+
+/// Size used when none is given: roughly the scale of a real puzzle input.
+pub const DEFAULT_SIZE: usize = 100;
+
+type Generator = fn(&mut Rng, usize) -> String;
+
+static GENERATORS: &[(u8, Generator)] = &[
+    (1, day_1::generate),
+    (2, day_2::generate),
+    (3, day_3::generate),
+    (4, day_4::generate),
+    (5, day_5::generate),
+    (6, day_6::generate),
+    (7, day_7::generate),
+];
+
+/// Days that have a generator.
+pub fn days() -> impl Iterator<Item = u8> {
+    GENERATORS.iter().map(|&(day, _)| day)
+}
+
+/// Generates an input for `day`, or `None` if the day has no generator.
+///
+/// `size` scales the input: the number of lines for list-like days and the
+/// side length for grid days. See each day's generator for the details.
+pub fn generate(day: u8, seed: u64, size: usize) -> Option<String> {
+    let (_, generator) = GENERATORS.iter().find(|&&(d, _)| d == day)?;
+    Some(generator(&mut Rng::new(seed), size))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::Solver;
+
+    fn solver(day: u8) -> &'static dyn Solver {
+        match day {
+            1 => &::day_1::Day1,
+            2 => &::day_2::Day2,
+            3 => &::day_3::Day3,
+            4 => &::day_4::Day4,
+            5 => &::day_5::Day5,
+            6 => &::day_6::Day6,
+            7 => &::day_7::Day7,
+            _ => unreachable!("no solver for day {}", day),
+        }
+    }
+
+    #[test]
+    fn test_generated_inputs_parse() {
+        for day in days() {
+            for seed in 0..20 {
+                for size in [1, 2, 10, 50] {
+                    let input = generate(day, seed, size).unwrap();
+                    if let Err(err) = solver(day).parse(&input) {
+                        panic!("day {} seed {} size {}:\n{}\n{}", day, seed, size, err.render(), input);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_generated_inputs_solve() {
+        for day in days() {
+            let input = generate(day, 1, 20).unwrap();
+            solver(day).solve(&input, &aoc_core::Part::ALL).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_same_seed_same_input() {
+        for day in days() {
+            assert_eq!(generate(day, 3, 30), generate(day, 3, 30));
+            assert_ne!(generate(day, 3, 30), generate(day, 4, 30));
+        }
+    }
+
+    #[test]
+    fn test_unknown_day() {
+        assert_eq!(generate(25, 0, 10), None);
+    }
+}
@@ -0,0 +1,131 @@
+use std::fmt;
+
+/// One of the eight compass directions on a grid, with up being towards
+/// row 0.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction {
+    /// The four orthogonal directions, clockwise from up.
+    pub const ORTHOGONAL: [Direction; 4] = [Direction::Up, Direction::Right, Direction::Down, Direction::Left];
+
+    /// The four diagonal directions, clockwise from up-right.
+    pub const DIAGONAL: [Direction; 4] =
+        [Direction::UpRight, Direction::DownRight, Direction::DownLeft, Direction::UpLeft];
+
+    /// All eight directions, clockwise from up.
+    pub const ALL: [Direction; 8] = [
+        Direction::Up,
+        Direction::UpRight,
+        Direction::Right,
+        Direction::DownRight,
+        Direction::Down,
+        Direction::DownLeft,
+        Direction::Left,
+        Direction::UpLeft,
+    ];
+
+    /// `(row, col)` offset of one step in this direction.
+    pub fn delta(self) -> (i32, i32) {
+        match self {
+            Direction::Up => (-1, 0),
+            Direction::UpRight => (-1, 1),
+            Direction::Right => (0, 1),
+            Direction::DownRight => (1, 1),
+            Direction::Down => (1, 0),
+            Direction::DownLeft => (1, -1),
+            Direction::Left => (0, -1),
+            Direction::UpLeft => (-1, -1),
+        }
+    }
+
+    /// The direction after a 90 degree turn to the right.
+    pub fn turn_right(self) -> Direction {
+        self.rotate(2)
+    }
+
+    /// The direction after a 90 degree turn to the left.
+    pub fn turn_left(self) -> Direction {
+        self.rotate(6)
+    }
+
+    /// The direction after a 45 degree turn to the right.
+    pub fn turn_right_45(self) -> Direction {
+        self.rotate(1)
+    }
+
+    /// The direction after a 45 degree turn to the left.
+    pub fn turn_left_45(self) -> Direction {
+        self.rotate(7)
+    }
+
+    /// The direction pointing the other way.
+    pub fn opposite(self) -> Direction {
+        self.rotate(4)
+    }
+
+    /// Whether this is one of up, right, down or left.
+    pub fn is_orthogonal(self) -> bool {
+        Direction::ORTHOGONAL.contains(&self)
+    }
+
+    /// Turns clockwise by `eighths` of a full turn.
+    fn rotate(self, eighths: usize) -> Direction {
+        let index = Direction::ALL.iter().position(|&d| d == self).unwrap();
+        Direction::ALL[(index + eighths) % Direction::ALL.len()]
+    }
+}
+
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Direction::Up => "up",
+            Direction::UpRight => "up-right",
+            Direction::Right => "right",
+            Direction::DownRight => "down-right",
+            Direction::Down => "down",
+            Direction::DownLeft => "down-left",
+            Direction::Left => "left",
+            Direction::UpLeft => "up-left",
+        };
+        f.write_str(name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_turning_right_four_times_returns_to_start() {
+        for dir in Direction::ALL {
+            assert_eq!(dir.turn_right().turn_right().turn_right().turn_right(), dir);
+            assert_eq!(dir.turn_right().turn_left(), dir);
+        }
+    }
+
+    #[test]
+    fn test_opposite_negates_delta() {
+        for dir in Direction::ALL {
+            let (row, col) = dir.delta();
+            assert_eq!(dir.opposite().delta(), (-row, -col));
+        }
+    }
+
+    #[test]
+    fn test_orthogonal_directions_turn_into_each_other() {
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Left.turn_right(), Direction::Up);
+        assert_eq!(Direction::Up.turn_right_45(), Direction::UpRight);
+        assert!(Direction::DIAGONAL.iter().all(|dir| !dir.is_orthogonal()));
+    }
+}
@@ -0,0 +1,249 @@
+use crate::Direction;
+use aoc_core::ParseError;
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+/// A cell of a grid. Coordinates are signed so that steps off the grid can
+/// be represented.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Position {
+    /// Row, counted from the top.
+    pub row: i32,
+    /// Column, counted from the left.
+    pub col: i32,
+}
+
+impl Position {
+    pub fn new(row: i32, col: i32) -> Position {
+        Position { row, col }
+    }
+
+    /// The position one step away in `dir`.
+    pub fn step(self, dir: Direction) -> Position {
+        self.offset(dir, 1)
+    }
+
+    /// The position `steps` steps away in `dir`.
+    pub fn offset(self, dir: Direction, steps: i32) -> Position {
+        let (delta_row, delta_col) = dir.delta();
+        Position {
+            row: self.row + delta_row * steps,
+            col: self.col + delta_col * steps,
+        }
+    }
+}
+
+/// A rectangular grid of cells, stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// A `width` by `height` grid with every cell set to `fill`.
+    pub fn new(width: usize, height: usize, fill: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid { width, height, cells: vec![fill; width * height] }
+    }
+
+    /// Builds a grid from rows, which must all have the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Grid<T> {
+        let width = rows.first().map_or(0, Vec::len);
+        assert!(rows.iter().all(|row| row.len() == width), "grid rows must all have the same length");
+        Grid { width, height: rows.len(), cells: rows.into_iter().flatten().collect() }
+    }
+
+    /// Parses one cell per character, skipping blank lines.
+    ///
+    /// `cell` turns a character into a cell or returns an error message,
+    /// which is reported pointing at that character. Rows of a different
+    /// length than the first are rejected.
+    pub fn parse(
+        day: u8,
+        input: &str,
+        mut cell: impl FnMut(Position, char) -> Result<T, String>,
+    ) -> Result<Grid<T>, ParseError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+
+        for line in input.lines().filter(|line| !line.trim().is_empty()) {
+            let line = line.trim_end_matches('\r');
+            let expected = *width.get_or_insert(line.chars().count());
+            if line.chars().count() != expected {
+                return Err(ParseError::at(
+                    day,
+                    input,
+                    line,
+                    format!("expected {} cells per row like the first row", expected),
+                ));
+            }
+            for (col, (offset, c)) in line.char_indices().enumerate() {
+                let pos = Position::new(height as i32, col as i32);
+                let value = cell(pos, c)
+                    .map_err(|message| ParseError::at(day, input, &line[offset..offset + c.len_utf8()], message))?;
+                cells.push(value);
+            }
+            height += 1;
+        }
+
+        Ok(Grid { width: width.unwrap_or(0), height, cells })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Whether `pos` lies on the grid.
+    pub fn contains(&self, pos: Position) -> bool {
+        pos.row >= 0 && (pos.row as usize) < self.height && pos.col >= 0 && (pos.col as usize) < self.width
+    }
+
+    /// The cell at `pos`, or `None` off the grid.
+    pub fn get(&self, pos: Position) -> Option<&T> {
+        self.index_of(pos).map(|i| &self.cells[i])
+    }
+
+    /// The cell at `pos`, or `None` off the grid.
+    pub fn get_mut(&mut self, pos: Position) -> Option<&mut T> {
+        self.index_of(pos).map(|i| &mut self.cells[i])
+    }
+
+    /// Every position of the grid, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |col| Position::new(row as i32, col as i32)))
+    }
+
+    /// Every position together with its cell, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// The rows of the grid, top to bottom.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `max(1)` keeps `chunks` happy on a zero-width grid, which has no cells
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// The neighbours of `pos` in `directions` that lie on the grid.
+    pub fn neighbours<'a>(
+        &'a self,
+        pos: Position,
+        directions: &'a [Direction],
+    ) -> impl Iterator<Item = (Position, &'a T)> + 'a {
+        directions.iter().filter_map(move |&dir| {
+            let next = pos.step(dir);
+            self.get(next).map(|cell| (next, cell))
+        })
+    }
+
+    /// The first position, row by row, whose cell equals `value`.
+    pub fn find(&self, value: &T) -> Option<Position>
+    where
+        T: PartialEq,
+    {
+        self.iter().find(|(_, cell)| *cell == value).map(|(pos, _)| pos)
+    }
+
+    /// Every position whose cell equals `value`, row by row.
+    pub fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Position> + 'a
+    where
+        T: PartialEq,
+    {
+        self.iter().filter(move |(_, cell)| *cell == value).map(|(pos, _)| pos)
+    }
+
+    fn index_of(&self, pos: Position) -> Option<usize> {
+        self.contains(pos).then(|| pos.row as usize * self.width + pos.col as usize)
+    }
+}
+
+impl Grid<char> {
+    /// Parses any text into a grid of its characters, skipping blank lines.
+    pub fn parse_chars(day: u8, input: &str) -> Result<Grid<char>, ParseError> {
+        Grid::parse(day, input, |_, c| Ok(c))
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Position) -> &T {
+        self.get(pos).unwrap_or_else(|| panic!("{:?} is outside the {}x{} grid", pos, self.width, self.height))
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, pos: Position) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(pos).unwrap_or_else(|| panic!("{:?} is outside the {}x{} grid", pos, width, height))
+    }
+}
+
+impl fmt::Display for Grid<char> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            writeln!(f, "{}", row.iter().collect::<String>())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_access() {
+        let grid = Grid::parse_chars(1, "ab\ncd\n").unwrap();
+        assert_eq!((grid.width(), grid.height()), (2, 2));
+        assert_eq!(grid[Position::new(1, 0)], 'c');
+        assert_eq!(grid.get(Position::new(2, 0)), None);
+        assert_eq!(grid.get(Position::new(0, -1)), None);
+        assert_eq!(grid.to_string(), "ab\ncd\n");
+    }
+
+    #[test]
+    fn test_parse_rejects_ragged_rows() {
+        let err = Grid::parse_chars(4, "abc\nab\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+        assert_eq!(err.message, "expected 3 cells per row like the first row");
+    }
+
+    #[test]
+    fn test_parse_reports_cell_errors_at_the_cell() {
+        let err = Grid::parse(6, ".#\n.x\n", |_, c| match c {
+            '.' => Ok(false),
+            '#' => Ok(true),
+            _ => Err("expected '.' or '#'".to_string()),
+        })
+        .unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 2, "x"));
+    }
+
+    #[test]
+    fn test_empty_input_is_an_empty_grid() {
+        let grid = Grid::parse_chars(1, "").unwrap();
+        assert_eq!((grid.width(), grid.height()), (0, 0));
+        assert_eq!(grid.positions().count(), 0);
+        assert_eq!(grid.to_string(), "");
+    }
+
+    #[test]
+    fn test_neighbours_stay_on_the_grid() {
+        let grid = Grid::parse_chars(1, "abc\ndef\nghi\n").unwrap();
+        let corner: Vec<char> = grid.neighbours(Position::new(0, 0), &Direction::ALL).map(|(_, &c)| c).collect();
+        assert_eq!(corner, vec!['b', 'e', 'd']);
+        assert_eq!(grid.neighbours(Position::new(1, 1), &Direction::ALL).count(), 8);
+        assert_eq!(grid.find(&'f'), Some(Position::new(1, 2)));
+    }
+}
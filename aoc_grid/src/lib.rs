@@ -0,0 +1,7 @@
+//! Rectangular grids of cells, as used by the map and word-search puzzles.
+
+mod direction;
+mod grid;
+
+pub use direction::Direction;
+pub use grid::{Grid, Position};
@@ -0,0 +1,176 @@
+//! Day 1: Historian Hysteria.
+//!
+//! Compares two lists of location IDs, first by pairing them up smallest to
+//! smallest, then by how often each left ID appears in the right list.
+#![warn(missing_docs)]
+
+use aoc_core::{parse_number, Answer, ParseError, Solution};
+
+/// The two columns of location IDs from the puzzle input.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LocationLists {
+    /// IDs from the left column, in input order.
+    pub left: Vec<i32>,
+    /// IDs from the right column, in input order.
+    pub right: Vec<i32>,
+}
+
+/// [`Solution`] for day 1.
+pub struct Day1;
+
+impl Solution for Day1 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 1;
+    type Input = LocationLists;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut lists = LocationLists::default();
+
+        for line in input.lines() {
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let numbers = line
+                .split_whitespace()
+                .map(|s| parse_number(Self::DAY, input, s))
+                .collect::<Result<Vec<i32>, _>>()?;
+
+            if numbers.len() != 2 {
+                return Err(ParseError::at(Self::DAY, input, line, "expected two location IDs"));
+            }
+            lists.left.push(numbers[0]);
+            lists.right.push(numbers[1]);
+        }
+
+        Ok(lists)
+    }
+
+    fn part_one(lists: &Self::Input) -> Answer {
+        part_one(lists).into()
+    }
+
+    fn part_two(lists: &Self::Input) -> Answer {
+        part_two(lists).into()
+    }
+}
+
+/// Total distance between the lists when the smallest remaining IDs of each
+/// list are paired up one after another.
+pub fn part_one(lists: &LocationLists) -> i32 {
+    let mut vec_1 = lists.left.clone();
+    let mut vec_2 = lists.right.clone();
+    let mut distance = 0;
+
+    while !vec_1.is_empty() {
+        let min_index = find_min_value_index(&vec_1);
+        let min_index_2 = find_min_value_index(&vec_2);
+
+        distance += (vec_1[min_index] - vec_2[min_index_2]).abs();
+        vec_1.remove(min_index);
+        vec_2.remove(min_index_2);
+    }
+
+    distance
+}
+
+/// Index of the first smallest value in `arr`.
+///
+/// # Panics
+///
+/// Panics if `arr` is empty.
+pub fn find_min_value_index(arr: &[i32]) -> usize {
+    let mut min_value = arr[0];
+    let mut min_index = 0;
+    for (i, &value) in arr.iter().enumerate() {
+        if value < min_value {
+            min_value = value;
+            min_index = i;
+        }
+    }
+    min_index
+}
+
+/// Similarity score: every left ID multiplied by the number of times it
+/// appears in the right list, summed up.
+pub fn part_two(lists: &LocationLists) -> i32 {
+    let mut score = 0;
+
+    for left in &lists.left {
+        for right in &lists.right {
+            if left == right {
+                score += left;
+            }
+        }
+    }
+
+    score
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = "3   4
+4   3
+2   5
+1   3
+3   9
+3   3";
+
+    #[test]
+    fn test_example_part1() {
+        assert_eq!(Day1::part_one(&Day1::parse(EXAMPLE).unwrap()), Answer::from(11));
+    }
+
+    #[test]
+    fn test_example_part2() {
+        assert_eq!(Day1::part_two(&Day1::parse(EXAMPLE).unwrap()), Answer::from(31));
+    }
+
+    #[test]
+    fn test_empty_input() {
+        let lists = Day1::parse("").unwrap();
+        assert_eq!(Day1::part_one(&lists), Answer::from(0));
+        assert_eq!(Day1::part_two(&lists), Answer::from(0));
+    }
+
+    #[test]
+    fn test_single_line() {
+        let lists = Day1::parse("5   3\n").unwrap();
+        assert_eq!(Day1::part_one(&lists), Answer::from(2));
+        assert_eq!(Day1::part_two(&lists), Answer::from(0));
+    }
+
+    #[test]
+    fn test_ragged_row_is_rejected() {
+        let err = Day1::parse("1   2\n3   4   5\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+    }
+
+    proptest! {
+        #[test]
+        fn prop_distance_is_symmetric(pairs in vec((0..100_000i32, 0..100_000i32), 0..50)) {
+            let (left, right): (Vec<i32>, Vec<i32>) = pairs.into_iter().unzip();
+            let swapped = LocationLists { left: right.clone(), right: left.clone() };
+            prop_assert_eq!(part_one(&LocationLists { left, right }), part_one(&swapped));
+        }
+
+        #[test]
+        fn prop_distance_ignores_input_order(pairs in vec((0..100_000i32, 0..100_000i32), 0..50)) {
+            let (mut left, right): (Vec<i32>, Vec<i32>) = pairs.into_iter().unzip();
+            let before = part_one(&LocationLists { left: left.clone(), right: right.clone() });
+            left.reverse();
+            prop_assert_eq!(part_one(&LocationLists { left, right }), before);
+        }
+
+        #[test]
+        fn prop_identical_lists_have_no_distance(ids in vec(0..100_000i32, 0..50)) {
+            let lists = LocationLists { left: ids.clone(), right: ids };
+            prop_assert_eq!(part_one(&lists), 0);
+            prop_assert!(part_two(&lists) >= lists.left.iter().sum::<i32>());
+        }
+    }
+}
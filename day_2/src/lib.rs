@@ -0,0 +1,152 @@
+//! Day 2: Red-Nosed Reports.
+//!
+//! Counts reports whose levels change gradually in a single direction,
+//! optionally tolerating one bad level.
+#![warn(missing_docs)]
+
+use aoc_core::{par_map, parse_number, Answer, ParseError, Solution};
+
+/// One report: a row of levels.
+pub type Report = Vec<i32>;
+
+/// [`Solution`] for day 2.
+pub struct Day2;
+
+impl Solution for Day2 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 2;
+    type Input = Vec<Report>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| line.split_whitespace().map(|s| parse_number(Self::DAY, input, s)).collect())
+            .collect()
+    }
+
+    fn part_one(levels: &Self::Input) -> Answer {
+        part_one(levels).into()
+    }
+
+    fn part_two(levels: &Self::Input) -> Answer {
+        part_two(levels).into()
+    }
+}
+
+/// Number of safe reports: levels strictly increase or strictly decrease, by
+/// 1 to 3 at each step.
+pub fn part_one(levels: &[Report]) -> i32 {
+    count(par_map(levels, |level| is_safe(level)))
+}
+
+/// Number of reports that are safe, or become safe after removing a single
+/// level.
+pub fn part_two(levels: &[Report]) -> i32 {
+    count(par_map(levels, |level| {
+        (0..level.len()).any(|i| {
+            let mut shortened_level = level.clone();
+            shortened_level.remove(i);
+            is_safe(&shortened_level)
+        })
+    }))
+}
+
+/// Whether a single report is safe.
+fn is_safe(level: &[i32]) -> bool {
+    let mut is_increasing: Option<bool> = None;
+
+    for i in 0..level.len().saturating_sub(1) {
+        let first = level[i];
+        let second = level[i + 1];
+        let diff = second - first;
+        
+        if diff == 0 {
+            return false;
+        }
+
+        if is_increasing.is_none() {
+            is_increasing = Some(diff > 0);
+        }
+
+        if (is_increasing.unwrap() && !(1..=3).contains(&diff)) ||
+           (!is_increasing.unwrap() && !(-3..=-1).contains(&diff)) {
+            return false;
+        }
+    }
+
+    true
+}
+
+fn count(safe: Vec<bool>) -> i32 {
+    safe.into_iter().filter(|&safe| safe).count() as i32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = "7 6 4 2 1
+1 2 7 8 9
+9 7 6 2 1
+1 3 2 4 5
+8 6 4 4 1
+1 3 6 7 9";
+
+    #[test]
+    fn test_example_part1() {
+        assert_eq!(Day2::part_one(&Day2::parse(EXAMPLE).unwrap()), Answer::from(2));
+    }
+
+    #[test]
+    fn test_example_part2() {
+        assert_eq!(Day2::part_two(&Day2::parse(EXAMPLE).unwrap()), Answer::from(4));
+    }
+
+    #[test]
+    fn test_empty_input() {
+        let reports = Day2::parse("").unwrap();
+        assert_eq!(Day2::part_one(&reports), Answer::from(0));
+        assert_eq!(Day2::part_two(&reports), Answer::from(0));
+    }
+
+    #[test]
+    fn test_single_level_report_is_safe() {
+        let reports = Day2::parse("5\n").unwrap();
+        assert_eq!(Day2::part_one(&reports), Answer::from(1));
+        assert_eq!(Day2::part_two(&reports), Answer::from(1));
+    }
+
+    #[test]
+    fn test_invalid_level_is_rejected() {
+        let err = Day2::parse("1 2 3\n4 x 6\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 3));
+    }
+
+    proptest! {
+        #[test]
+        fn prop_dampener_never_loses_safe_reports(reports in vec(vec(1..100i32, 1..10), 0..30)) {
+            prop_assert!(part_two(&reports) >= part_one(&reports));
+        }
+
+        #[test]
+        fn prop_safety_ignores_direction(reports in vec(vec(1..100i32, 1..10), 0..30)) {
+            let reversed: Vec<Report> = reports.iter().map(|r| r.iter().rev().copied().collect()).collect();
+            prop_assert_eq!(part_one(&reversed), part_one(&reports));
+            prop_assert_eq!(part_two(&reversed), part_two(&reports));
+        }
+
+        #[test]
+        fn prop_gradual_reports_are_safe(start in 1..100i32, steps in vec(1..=3i32, 0..10)) {
+            let report: Report = std::iter::once(start)
+                .chain(steps.iter().scan(start, |level, step| {
+                    *level += step;
+                    Some(*level)
+                }))
+                .collect();
+            prop_assert_eq!(part_one(&[report]), 1);
+        }
+    }
+}
@@ -0,0 +1,149 @@
+//! Day 3: Mull It Over.
+//!
+//! Scans corrupted memory for `mul(X,Y)` instructions, optionally honouring
+//! `do()` and `don't()` switches. As the puzzle says, X and Y have one to
+//! three digits: `mul(1234,5)` is not an instruction.
+#![warn(missing_docs)]
+
+use aoc_core::{Answer, ParseError, Solution};
+use regex::Regex;
+
+/// [`Solution`] for day 3. The input is kept as the raw memory dump.
+pub struct Day3;
+
+impl Solution for Day3 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 3;
+    type Input = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(input.to_string())
+    }
+
+    fn part_one(input: &Self::Input) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input) -> Answer {
+        part_two(input).into()
+    }
+}
+
+/// Whether `s` is a valid `mul` operand: a number of one to three digits.
+fn is_operand(s: &str) -> bool {
+    (1..=3).contains(&s.len()) && s.bytes().all(|b| b.is_ascii_digit())
+}
+
+/// Sum of the products of every well-formed `mul(X,Y)` instruction, where X
+/// and Y are numbers of one to three digits.
+pub fn part_one(input: &str) -> i64 {
+    let mut result = 0;
+
+    // Split by "mul(" and process each token
+    input.split("mul(").skip(1).for_each(|token| {
+        if let Some(end_idx) = token.find(')') {
+            let numbers = &token[..end_idx];
+            if let Some((num1, num2)) = numbers.split_once(',') {
+                if is_operand(num1) && is_operand(num2) {
+                    result += num1.parse::<i64>().unwrap() * num2.parse::<i64>().unwrap();
+                }
+            }
+        }
+    });
+
+    result
+}
+
+/// Like [`part_one`], but `don't()` disables the following instructions until
+/// the next `do()`.
+pub fn part_two(input: &str) -> i64 {
+    let mut result = 0;
+    let mut mult_enabled: bool = true;
+    // Split by "mul(" and process each token
+    let re = Regex::new(r"mul\(([0-9]{1,3}),([0-9]{1,3})\)|don't\(\)|do\(\)").unwrap();
+    re.find_iter(input).for_each(|token| {
+        if token.as_str() == "do()" {
+            mult_enabled = true;
+        } else if token.as_str() == "don't()" {
+            mult_enabled = false;
+        } else if mult_enabled {
+            let token_str = token.as_str();
+            // Remove "mul(" from start and ")" from end
+            let numbers = &token_str[4..token_str.len()-1];
+            if let Some((num1, num2)) = numbers.split_once(',') {
+                if let (Ok(n1), Ok(n2)) = (num1.parse::<i64>(), num2.parse::<i64>()) {
+                    result += n1 * n2;
+                }
+            }
+        }
+    });
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_example_part1() {
+        let input = "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))";
+        assert_eq!(Day3::part_one(&Day3::parse(input).unwrap()), Answer::from(161));
+    }
+
+    #[test]
+    fn test_example_part2() {
+        let input = "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+        assert_eq!(Day3::part_two(&Day3::parse(input).unwrap()), Answer::from(48));
+    }
+
+    #[test]
+    fn test_empty_input() {
+        let memory = Day3::parse("").unwrap();
+        assert_eq!(Day3::part_one(&memory), Answer::from(0));
+        assert_eq!(Day3::part_two(&memory), Answer::from(0));
+    }
+
+    #[test]
+    fn test_operands_have_at_most_three_digits() {
+        let memory = Day3::parse("mul(1234,5)mul(5,1234)mul(999,2)mul(,2)").unwrap();
+        assert_eq!(Day3::part_one(&memory), Answer::from(1998));
+        assert_eq!(Day3::part_two(&memory), Answer::from(1998));
+    }
+
+    #[test]
+    fn test_sum_can_pass_i32_max() {
+        let memory = "mul(999,999)".repeat(3000);
+        assert_eq!(part_one(&memory), 2_994_003_000);
+        assert_eq!(part_two(&memory), 2_994_003_000);
+    }
+
+    #[test]
+    fn test_instructions_span_lines() {
+        let memory = Day3::parse("mul(2,3)don't()\nmul(4,5)\ndo()mul(1,1)").unwrap();
+        assert_eq!(Day3::part_one(&memory), Answer::from(27));
+        assert_eq!(Day3::part_two(&memory), Answer::from(7));
+    }
+
+    proptest! {
+        #[test]
+        fn prop_single_instruction_is_its_product(x in 0..1000i64, y in 0..1000i64) {
+            let memory = format!("xmul({},{})]", x, y);
+            prop_assert_eq!(part_one(&memory), x * y);
+            prop_assert_eq!(part_two(&memory), x * y);
+        }
+
+        #[test]
+        fn prop_switches_only_ever_disable(
+            memory in r"(mul\([0-9]{1,3},[0-9]{1,3}\)|do\(\)|don't\(\)|[a-z()!,]){0,40}"
+        ) {
+            prop_assert!(part_two(&memory) <= part_one(&memory));
+        }
+
+        #[test]
+        fn prop_leading_do_changes_nothing(memory in r"(mul\([0-9]{1,3},[0-9]{1,3}\)|don't\(\)|[a-z()!,]){0,40}") {
+            prop_assert_eq!(part_two(&format!("do(){}", memory)), part_two(&memory));
+        }
+    }
+}
@@ -0,0 +1,168 @@
+//! Day 4: Ceres Search.
+//!
+//! Counts occurrences of `XMAS` in a word search, then `MAS` crosses.
+#![warn(missing_docs)]
+
+use aoc_core::{Answer, ParseError, Solution};
+use aoc_grid::{Direction, Grid, Position};
+
+/// Word searched for in every direction in part one.
+pub const CHECK_WORD: &str = "XMAS";
+/// Word that has to appear twice, crossing diagonally, in part two.
+pub const CHECK_WORD_TWO: &str = "MAS";
+
+/// The letter grid; every row has the same length.
+pub type WordSearch = Grid<char>;
+
+/// [`Solution`] for day 4.
+pub struct Day4;
+
+impl Solution for Day4 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 4;
+    type Input = WordSearch;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Grid::parse_chars(Self::DAY, input)
+    }
+
+    fn part_one(input: &Self::Input) -> Answer {
+        part_one(input, CHECK_WORD).into()
+    }
+
+    fn part_two(input: &Self::Input) -> Answer {
+        part_two(input, CHECK_WORD_TWO).into()
+    }
+}
+
+/// Whether `word` can be read starting at `start` and stepping in `dir`.
+fn reads_word(grid: &WordSearch, start: Position, dir: Direction, word: &str) -> bool {
+    word.chars()
+        .enumerate()
+        .all(|(i, letter)| grid.get(start.offset(dir, i as i32)) == Some(&letter))
+}
+
+/// Number of times `check_word` appears horizontally, vertically or
+/// diagonally, forwards or backwards.
+pub fn part_one(input: &WordSearch, check_word: &str) -> i32 {
+    let mut total_matches = 0;
+
+    for pos in input.positions() {
+        for dir in Direction::ALL {
+            if reads_word(input, pos, dir, check_word) {
+                total_matches += 1;
+            }
+        }
+    }
+
+    total_matches
+}
+
+/// Number of cells where two diagonal `check_word`s cross on their middle
+/// letter, forming an X.
+pub fn part_two(input: &WordSearch, check_word: &str) -> i32 {
+    let mut total_matches = 0;
+    let middle = (check_word.chars().count() / 2) as i32;
+
+    for pos in input.positions() {
+        // Read each diagonal so that its middle letter lands on this cell
+        let matches_on_this_letter = Direction::DIAGONAL
+            .into_iter()
+            .filter(|&dir| reads_word(input, pos.offset(dir.opposite(), middle), dir, check_word))
+            .count();
+
+        if matches_on_this_letter == 2 {
+            total_matches += 1;
+        }
+    }
+
+    total_matches
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = "MMMSXXMASM
+MSAMXMSMSA
+AMXSXMAAMM
+MSAMASMSMX
+XMASAMXAMM
+XXAMMXXAMA
+SMSMSASXSS
+SAXAMASAAA
+MAMMMXMMMM
+MXMXAXMASX";
+
+    #[test]
+    fn test_example_part1() {
+        assert_eq!(Day4::part_one(&Day4::parse(EXAMPLE).unwrap()), Answer::from(18));
+    }
+
+    #[test]
+    fn test_example_part2() {
+        assert_eq!(Day4::part_two(&Day4::parse(EXAMPLE).unwrap()), Answer::from(9));
+    }
+
+    #[test]
+    fn test_empty_input() {
+        let grid = Day4::parse("").unwrap();
+        assert_eq!(Day4::part_one(&grid), Answer::from(0));
+        assert_eq!(Day4::part_two(&grid), Answer::from(0));
+    }
+
+    #[test]
+    fn test_single_line() {
+        let grid = Day4::parse("XMASAMX\n").unwrap();
+        assert_eq!(Day4::part_one(&grid), Answer::from(2));
+        assert_eq!(Day4::part_two(&grid), Answer::from(0));
+    }
+
+    #[test]
+    fn test_word_reading_up_from_bottom_row() {
+        // Words reading up that end on the top row used to be missed
+        let grid = Day4::parse("S\nA\nM\nX\n").unwrap();
+        assert_eq!(Day4::part_one(&grid), Answer::from(1));
+        let grid = Day4::parse("S..S\n.AA.\n.MM.\nX..X\n").unwrap();
+        assert_eq!(Day4::part_one(&grid), Answer::from(2));
+    }
+
+    #[test]
+    fn test_ragged_rows_are_rejected() {
+        let err = Day4::parse("XMAS\nXMA\nXMAS\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+    }
+
+    fn letters(size: usize) -> impl Strategy<Value = Vec<Vec<char>>> {
+        vec(vec(prop::sample::select(vec!['X', 'M', 'A', 'S']), size), size)
+    }
+
+    proptest! {
+        #[test]
+        fn prop_counts_ignore_mirroring(rows in (1..8usize).prop_flat_map(letters)) {
+            let mirrored: Vec<Vec<char>> = rows.iter().map(|row| row.iter().rev().copied().collect()).collect();
+            let (grid, mirrored) = (Grid::from_rows(rows), Grid::from_rows(mirrored));
+            prop_assert_eq!(part_one(&grid, CHECK_WORD), part_one(&mirrored, CHECK_WORD));
+            prop_assert_eq!(part_two(&grid, CHECK_WORD_TWO), part_two(&mirrored, CHECK_WORD_TWO));
+        }
+
+        #[test]
+        fn prop_counts_ignore_transposing(rows in (1..8usize).prop_flat_map(letters)) {
+            let transposed: Vec<Vec<char>> =
+                (0..rows.len()).map(|col| rows.iter().map(|row| row[col]).collect()).collect();
+            let (grid, transposed) = (Grid::from_rows(rows), Grid::from_rows(transposed));
+            prop_assert_eq!(part_one(&grid, CHECK_WORD), part_one(&transposed, CHECK_WORD));
+            prop_assert_eq!(part_two(&grid, CHECK_WORD_TWO), part_two(&transposed, CHECK_WORD_TWO));
+        }
+
+        #[test]
+        fn prop_palindromes_are_found_in_pairs(rows in (1..8usize).prop_flat_map(letters)) {
+            // Every match of a word that reads the same backwards is also a
+            // match in the opposite direction
+            let grid = Grid::from_rows(rows);
+            prop_assert_eq!(part_one(&grid, "AXA") % 2, 0);
+        }
+    }
+}
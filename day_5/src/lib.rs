@@ -0,0 +1,282 @@
+//! Day 5: Print Queue.
+//!
+//! Checks safety manual updates against page ordering rules and fixes the
+//! ones printed out of order.
+#![warn(missing_docs)]
+
+use aoc_core::{par_map, parse_number, Answer, ParseError, Solution};
+use std::collections::{HashMap, HashSet};
+
+/// Page ordering rules: each key must be printed before every page in its set.
+pub type Rules = HashMap<i32, HashSet<i32>>;
+
+/// The parsed puzzle input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PrintQueue {
+    /// All `X|Y` ordering rules.
+    pub rules: Rules,
+    /// The page numbers of each update, in print order.
+    pub updates: Vec<Vec<i32>>,
+}
+
+/// [`Solution`] for day 5.
+pub struct Day5;
+
+impl Solution for Day5 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 5;
+    type Input = PrintQueue;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(queue: &Self::Input) -> Answer {
+        solve_part1(&queue.rules, &queue.updates).into()
+    }
+
+    fn part_two(queue: &Self::Input) -> Answer {
+        solve_part2(&queue.rules, &queue.updates).into()
+    }
+}
+
+/// Sum of the middle page numbers of the updates that are already correctly
+/// ordered.
+pub fn solve_part1(rules: &Rules, updates: &[Vec<i32>]) -> i32 {
+    let middles = par_map(updates, |update| {
+        if is_valid_order(update, rules) {
+            let middle_idx = update.len() / 2;
+            update[middle_idx]
+        } else {
+            0
+        }
+    });
+
+    middles.into_iter().sum()
+}
+
+/// Sum of the middle page numbers of the incorrectly ordered updates, after
+/// sorting them with [`sort_update`].
+pub fn solve_part2(rules: &Rules, updates: &[Vec<i32>]) -> i32 {
+    let middles = par_map(updates, |update| {
+        if !is_valid_order(update, rules) {
+            let sorted = sort_update(update, rules);
+            let middle_idx = sorted.len() / 2;
+            sorted[middle_idx]
+        } else {
+            0
+        }
+    });
+
+    middles.into_iter().sum()
+}
+
+fn parse_input(input: &str) -> Result<PrintQueue, ParseError> {
+    // `lines` drops the `\r` of CRLF line endings too, so the blank line is
+    // found whichever ending the file uses
+    let lines: Vec<&str> = input.lines().collect();
+    let Some(blank) = lines.iter().position(|line| line.is_empty()) else {
+        return Err(ParseError::at_end(
+            Day5::DAY,
+            input,
+            "expected a blank line between the ordering rules and the updates",
+        ));
+    };
+    let (rule_lines, update_lines) = (&lines[..blank], &lines[blank + 1..]);
+
+    // Parse rules into a HashMap where key must come before all values in the set
+    let mut rules: Rules = HashMap::new();
+    for &line in rule_lines {
+        let Some((before, after)) = line.split_once('|') else {
+            return Err(ParseError::at(Day5::DAY, input, line, "expected an ordering rule like 47|53"));
+        };
+        let before = parse_number(Day5::DAY, input, before)?;
+        let after = parse_number(Day5::DAY, input, after)?;
+        rules.entry(before).or_default().insert(after);
+    }
+
+    // Parse updates
+    let updates = update_lines
+        .iter()
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            line.split(',')
+                .map(|n| parse_number(Day5::DAY, input, n))
+                .collect()
+        })
+        .collect::<Result<Vec<Vec<i32>>, _>>()?;
+
+    Ok(PrintQueue { rules, updates })
+}
+
+/// Whether no rule requires a later page of `update` to come before an
+/// earlier one.
+pub fn is_valid_order(update: &[i32], rules: &Rules) -> bool {
+    // For each pair of numbers in the update
+    for i in 0..update.len() {
+        for j in i + 1..update.len() {
+            let before = update[i];
+            let after = update[j];
+
+            // Check if there's a rule saying after should come before before
+            if rules.get(&after).is_some_and(|set| set.contains(&before)) {
+                return false;
+            }
+        }
+    }
+    true
+}
+
+/// Returns `update` reordered so that it satisfies `rules`.
+#[tracing::instrument(level = "debug", skip_all, fields(pages = update.len()))]
+pub fn sort_update(update: &[i32], rules: &Rules) -> Vec<i32> {
+    let mut result = update.to_vec();
+    
+    // Bubble sort with custom comparison based on rules
+    for i in 0..result.len() {
+        for j in 0..result.len() - 1 - i {
+            let a = result[j];
+            let b = result[j + 1];
+            
+            // If b should come before a according to rules, swap them
+            if rules.get(&b).is_some_and(|set| set.contains(&a)) {
+                result.swap(j, j + 1);
+            }
+        }
+    }
+    
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_example_part1() {
+        let input = "47|53
+97|13
+97|61
+97|47
+75|29
+61|13
+75|53
+29|13
+97|29
+53|29
+61|53
+97|53
+61|29
+47|13
+75|47
+97|75
+47|61
+75|61
+47|29
+75|13
+53|13
+
+75,47,61,53,29
+97,61,53,29,13
+75,29,13
+75,97,47,61,53
+61,13,29
+97,13,75,29,47";
+
+        assert_eq!(Day5::part_one(&Day5::parse(input).unwrap()), Answer::from(143));
+    }
+
+    #[test]
+    fn test_example_part2() {
+        let input = "47|53
+97|13
+97|61
+97|47
+75|29
+61|13
+75|53
+29|13
+97|29
+53|29
+61|53
+97|53
+61|29
+47|13
+75|47
+97|75
+47|61
+75|61
+47|29
+75|13
+53|13
+
+75,47,61,53,29
+97,61,53,29,13
+75,29,13
+75,97,47,61,53
+61,13,29
+97,13,75,29,47";
+
+        assert_eq!(Day5::part_two(&Day5::parse(input).unwrap()), Answer::from(123));
+    }
+
+    #[test]
+    fn test_empty_input_is_rejected() {
+        assert!(Day5::parse("").is_err());
+    }
+
+    #[test]
+    fn test_single_rule_and_update() {
+        let queue = Day5::parse("1|2\n\n2,1\n").unwrap();
+        assert_eq!(Day5::part_one(&queue), Answer::from(0));
+        assert_eq!(Day5::part_two(&queue), Answer::from(2));
+    }
+
+    #[test]
+    fn test_invalid_page_is_rejected() {
+        let err = Day5::parse("1|2\n\n1,x\n").unwrap_err();
+        assert_eq!((err.line, err.column), (3, 3));
+    }
+
+    #[test]
+    fn test_crlf_line_endings() {
+        let PrintQueue { rules, updates } = Day5::parse("47|53\r\n\r\n75,47,53\r\n").unwrap();
+        assert!(rules[&47].contains(&53));
+        assert_eq!(updates, [vec![75, 47, 53]]);
+    }
+
+    /// Some pages in their one correct order, with a rule for every pair.
+    fn ordered_pages() -> impl Strategy<Value = (Vec<i32>, Rules)> {
+        Just((10..100).collect::<Vec<i32>>()).prop_shuffle().prop_map(|mut order| {
+            order.truncate(20);
+            let mut rules = Rules::new();
+            for (i, &before) in order.iter().enumerate() {
+                rules.entry(before).or_default().extend(&order[i + 1..]);
+            }
+            (order, rules)
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn prop_sorted_update_is_valid((order, rules) in ordered_pages(), picks in vec(0..20usize, 1..12)) {
+            let update: Vec<i32> = picks.iter().map(|&i| order[i]).collect();
+            let sorted = sort_update(&update, &rules);
+            prop_assert!(is_valid_order(&sorted, &rules));
+
+            let (mut before, mut after) = (update, sorted);
+            before.sort();
+            after.sort();
+            prop_assert_eq!(before, after);
+        }
+
+        #[test]
+        fn prop_valid_updates_stay_as_they_are((order, rules) in ordered_pages(), len in 1..20usize) {
+            let update = &order[..len];
+            prop_assert!(is_valid_order(update, &rules));
+            prop_assert_eq!(sort_update(update, &rules), update.to_vec());
+        }
+    }
+}
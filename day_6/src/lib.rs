@@ -0,0 +1,318 @@
+//! Day 6: Guard Gallivant.
+//!
+//! Follows a patrolling guard who turns right at every obstacle, then looks
+//! for single obstacles that would trap the guard in a loop.
+#![warn(missing_docs)]
+
+use aoc_core::{cancelled, par_map, Answer, ParseError, Progress, Solution};
+use aoc_grid::{Direction, Grid, Position};
+use std::collections::HashSet;
+
+/// [`Solution`] for day 6.
+pub struct Day6;
+
+impl Solution for Day6 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 6;
+    type Input = Map;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Map::from_input(input)
+    }
+
+    fn part_one(map: &Self::Input) -> Answer {
+        map.clone().simulate_guard_path().into()
+    }
+
+    fn part_two(map: &Self::Input) -> Answer {
+        map.count_loop_causing_positions().into()
+    }
+}
+
+/// The lab map together with the guard's current state.
+#[derive(Debug, Clone)]
+pub struct Map {
+    grid: Grid<char>,
+    guard_pos: Position,
+    guard_dir: Direction,
+    start_pos: Position,
+}
+
+impl Map {
+    /// Parses a rectangular map of `.`, `#` and exactly one `^` guard.
+    pub fn from_input(input: &str) -> Result<Self, ParseError> {
+        let mut guard = None;
+        let grid = Grid::parse(Day6::DAY, input, |pos, cell| match cell {
+            '.' | '#' => Ok(cell),
+            '^' if guard.is_none() => {
+                guard = Some(pos);
+                Ok(cell)
+            }
+            '^' => Err("the map has more than one guard".to_string()),
+            _ => Err("expected '.', '#' or '^'".to_string()),
+        })?;
+
+        let Some(guard_pos) = guard else {
+            return Err(ParseError::at_end(Day6::DAY, input, "the map has no guard ('^')"));
+        };
+
+        Ok(Map {
+            grid,
+            guard_pos,
+            guard_dir: Direction::Up,
+            start_pos: guard_pos,
+        })
+    }
+
+    /// The map's cells.
+    pub fn grid(&self) -> &Grid<char> {
+        &self.grid
+    }
+
+    /// Where the guard started, facing up.
+    pub fn start_pos(&self) -> Position {
+        self.start_pos
+    }
+
+    /// Whether `pos` holds an obstacle.
+    pub fn has_obstacle(&self, pos: Position) -> bool {
+        has_obstacle(&self.grid, pos)
+    }
+
+    /// Whether the guard, starting from `start_pos` facing up, eventually
+    /// walks off the map rather than looping forever.
+    pub fn guard_leaves(&self) -> bool {
+        let mut visited_states = HashSet::new();
+        let mut guard_pos = self.start_pos;
+        let mut guard_dir = Direction::Up;
+
+        while visited_states.insert((guard_pos, guard_dir)) {
+            let next_pos = guard_pos.step(guard_dir);
+
+            if !self.grid.contains(next_pos) {
+                return true;
+            }
+
+            if self.has_obstacle(next_pos) {
+                guard_dir = guard_dir.turn_right();
+            } else {
+                guard_pos = next_pos;
+            }
+        }
+        false
+    }
+
+    /// Walks the guard until they leave the map and returns the number of
+    /// distinct positions visited, moving the guard along the way.
+    ///
+    /// A guard who is trapped, or who walks in a loop, never leaves; the
+    /// walk stops once they are back where they were, facing the same way,
+    /// or early, with the count so far, once the run is [`cancelled`].
+    pub fn simulate_guard_path(&mut self) -> usize {
+        let mut visited = HashSet::new();
+        let mut visited_states = HashSet::new();
+        visited.insert(self.guard_pos);
+
+        while !cancelled() {
+            if !visited_states.insert((self.guard_pos, self.guard_dir)) {
+                break;
+            }
+
+            // Check position in front of guard
+            let next_pos = self.guard_pos.step(self.guard_dir);
+
+            if !self.grid.contains(next_pos) {
+                break;
+            }
+
+            if self.has_obstacle(next_pos) {
+                // Turn right if there's an obstacle
+                self.guard_dir = self.guard_dir.turn_right();
+            } else {
+                // Move forward
+                self.guard_pos = next_pos;
+                visited.insert(self.guard_pos);
+            }
+        }
+
+        visited.len()
+    }
+
+    /// Number of empty cells where a single new obstacle would trap the guard
+    /// in a loop.
+    pub fn count_loop_causing_positions(&self) -> usize {
+        // First, calculate the guard's normal path - O(M)
+        let mut normal_path = Vec::new();
+        let mut visited_states = HashSet::new();
+        let mut guard_pos = self.start_pos;
+        let mut guard_dir = Direction::Up;
+        
+        // Store the complete path until guard exits or loops
+        loop {
+            let state = (guard_pos, guard_dir);
+            if visited_states.contains(&state) {
+                break;
+            }
+            normal_path.push(state);
+            visited_states.insert(state);
+
+            let next_pos = guard_pos.step(guard_dir);
+
+            if !self.grid.contains(next_pos) {
+                break;
+            }
+
+            if self.has_obstacle(next_pos) {
+                guard_dir = guard_dir.turn_right();
+            } else {
+                guard_pos = next_pos;
+            }
+        }
+
+        // Collect empty positions
+        let positions: Vec<Position> = self.grid.find_all(&'.').collect();
+
+        // Process positions in parallel
+        let progress = Progress::new("day 6 obstacles", positions.len());
+        let creates_loop = par_map(&positions, |&pos| {
+            progress.inc();
+            if cancelled() {
+                return false;
+            }
+            // For each position, check if it intersects with the normal path
+            for &(path_pos, path_dir) in &normal_path {
+                if path_pos.step(path_dir) == pos {
+                    // This position would cause the guard to turn right
+                    return would_create_loop(path_pos, path_dir.turn_right(), pos, &self.grid);
+                }
+            }
+            false
+        });
+        progress.finish();
+
+        creates_loop.into_iter().filter(|&creates_loop| creates_loop).count()
+    }
+}
+
+/// Whether a guard at `start_pos` facing `start_dir` loops forever on `grid`
+/// with an extra obstacle at `obstacle_pos`.
+#[tracing::instrument(level = "debug", skip(grid))]
+pub fn would_create_loop(start_pos: Position, start_dir: Direction, obstacle_pos: Position, grid: &Grid<char>) -> bool {
+    let mut visited = HashSet::new();
+    let mut pos = start_pos;
+    let mut dir = start_dir;
+    let max_steps = grid.width() * grid.height() * 4;
+
+    for _ in 0..max_steps {
+        let state = (pos, dir);
+        if visited.contains(&state) {
+            return true;
+        }
+        visited.insert(state);
+
+        let next_pos = pos.step(dir);
+
+        if !grid.contains(next_pos) {
+            return false;
+        }
+
+        if has_obstacle(grid, next_pos) || next_pos == obstacle_pos {
+            dir = dir.turn_right();
+        } else {
+            pos = next_pos;
+        }
+    }
+    false
+}
+
+/// Whether `pos` holds an obstacle on `grid`.
+fn has_obstacle(grid: &Grid<char>, pos: Position) -> bool {
+    grid.get(pos) == Some(&'#')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::{solve_within, Part};
+    use proptest::prelude::*;
+    use std::time::Duration;
+
+    const EXAMPLE: &str = "....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...";
+
+    #[test]
+    fn test_example_part1() {
+        assert_eq!(Day6::part_one(&Day6::parse(EXAMPLE).unwrap()), Answer::from(41));
+    }
+
+    #[test]
+    fn test_example_part2() {
+        assert_eq!(Day6::part_two(&Day6::parse(EXAMPLE).unwrap()), Answer::from(6));
+    }
+
+    #[test]
+    fn test_empty_input_has_no_guard() {
+        let err = Day6::parse("").unwrap_err();
+        assert_eq!(err.message, "the map has no guard ('^')");
+    }
+
+    #[test]
+    fn test_single_line() {
+        let map = Day6::parse("..^..\n").unwrap();
+        assert_eq!(Day6::part_one(&map), Answer::from(1));
+        assert_eq!(Day6::part_two(&map), Answer::from(0));
+    }
+
+    #[test]
+    fn test_ragged_rows_are_rejected() {
+        let err = Day6::parse("..^.\n...\n....\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+    }
+
+    #[test]
+    fn test_second_guard_is_rejected() {
+        let err = Day6::parse("..^.\n.^..\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 2));
+    }
+
+    #[test]
+    fn test_guard_leaves() {
+        assert!(Map::from_input(EXAMPLE).unwrap().guard_leaves());
+        assert!(!Map::from_input(".#.\n#^#\n.#.\n").unwrap().guard_leaves());
+        assert!(!Map::from_input(".#...\n....#\n.....\n#^...\n...#.\n").unwrap().guard_leaves());
+    }
+
+    #[test]
+    fn test_trapped_guard_stops() {
+        let parsed = aoc_core::Solver::parse(&Day6, ".#.\n#^#\n.#.\n").unwrap();
+        let answer = solve_within(&Day6, &parsed, Part::One, Some(Duration::from_millis(500)));
+        assert_eq!(answer, Some(Answer::from(1)));
+    }
+
+    #[test]
+    fn test_looping_guard_stops() {
+        let map = Day6::parse(".#...\n....#\n.....\n#^...\n...#.\n").unwrap();
+        assert_eq!(Day6::part_one(&map), Answer::from(8));
+    }
+
+    proptest! {
+        #[test]
+        fn prop_visits_stay_within_free_cells(seed in any::<u64>(), size in 1..15usize) {
+            let map = Day6::parse(&aoc_gen::generate(Day6::DAY, seed, size).unwrap()).unwrap();
+            let free = map.grid().iter().filter(|&(_, &cell)| cell != '#').count();
+            let empty = map.grid().find_all(&'.').count();
+
+            let visited = map.clone().simulate_guard_path();
+            prop_assert!(visited >= 1 && visited <= free);
+            prop_assert!(map.count_loop_causing_positions() <= empty);
+        }
+    }
+}
@@ -0,0 +1,266 @@
+//! Day 7: Bridge Repair.
+//!
+//! Finds calibration equations that can be made true by inserting `+`, `*`
+//! and, in part two, `||` (concatenation) operators, evaluated left to right.
+#![warn(missing_docs)]
+
+use aoc_core::{cancelled, par_map, parse_number, Answer, ParseError, Progress, Solution};
+
+/// One calibration equation, `test_value: operands...`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Equation {
+    /// The value the operands have to combine to.
+    pub test_value: i64,
+    /// The operands, in order; never empty.
+    pub operands: Vec<i64>,
+}
+
+/// [`Solution`] for day 7.
+pub struct Day7;
+
+impl Solution for Day7 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 7;
+    type Input = Vec<Equation>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| {
+                let numbers = extract_numbers(input, line)?;
+                Ok(Equation { test_value: numbers[0], operands: numbers[1..].to_vec() })
+            })
+            .collect()
+    }
+
+    fn part_one(equations: &Self::Input) -> Answer {
+        part_one(equations).into()
+    }
+
+    fn part_two(equations: &Self::Input) -> Answer {
+        part_two(equations).into()
+    }
+}
+
+/// Total calibration result using `+` and `*`.
+pub fn part_one(equations: &[Equation]) -> i64 {
+    total_calibration_result(equations, find_valid_operations)
+}
+
+/// Total calibration result using `+`, `*` and `||`.
+pub fn part_two(equations: &[Equation]) -> i64 {
+    total_calibration_result(equations, find_valid_operations_part_2)
+}
+
+fn extract_numbers(input: &str, line: &str) -> Result<Vec<i64>, ParseError> {
+    let Some((test_value, operands)) = line.split_once(':') else {
+        return Err(ParseError::at(Day7::DAY, input, line, "expected an equation like '190: 10 19'"));
+    };
+    let mut numbers = vec![parse_number(Day7::DAY, input, test_value.trim())?];
+    for operand in operands.split_whitespace() {
+        numbers.push(parse_number(Day7::DAY, input, operand)?);
+    }
+    if numbers.len() < 2 {
+        return Err(ParseError::at(Day7::DAY, input, line, "expected at least one operand after ':'"));
+    }
+    Ok(numbers)
+}
+
+/// Number of ways to insert `+` and `*` between `result` and `operations`
+/// that evaluate to `expected_result`. `result` is the equation's first
+/// operand and `operations` the rest.
+///
+/// Branches that overflow an `i64`, or that have passed `expected_result`
+/// with nothing left that could bring them back down, are dropped. The
+/// search is exponential in the number of operands; once the run is
+/// [`cancelled`] it stops and returns 0.
+pub fn find_valid_operations(expected_result: i64, operations: Vec<i64>, result: i64) -> i64 {
+    if cancelled() || is_out_of_reach(expected_result, &operations, result) {
+        return 0;
+    }
+    let Some((&next, rest)) = operations.split_first() else {
+        return i64::from(result == expected_result);
+    };
+
+    [result.checked_mul(next), result.checked_add(next)]
+        .into_iter()
+        .flatten()
+        .map(|result| find_valid_operations(expected_result, rest.to_vec(), result))
+        .sum()
+}
+
+/// Like [`find_valid_operations`], but also tries concatenating the digits
+/// of the running result and the next operand.
+pub fn find_valid_operations_part_2(expected_result: i64, operations: Vec<i64>, result: i64) -> i64 {
+    if cancelled() || is_out_of_reach(expected_result, &operations, result) {
+        return 0;
+    }
+    let Some((&next, rest)) = operations.split_first() else {
+        return i64::from(result == expected_result);
+    };
+
+    [result.checked_mul(next), result.checked_add(next), concatenate(result, next)]
+        .into_iter()
+        .flatten()
+        .map(|result| find_valid_operations_part_2(expected_result, rest.to_vec(), result))
+        .sum()
+}
+
+/// Whether `result` is already past `expected_result` and no operator could
+/// bring it back: `+`, `*` and `||` only grow a non-negative value when
+/// every remaining operand is at least 1, whereas `* 0` can reset it.
+fn is_out_of_reach(expected_result: i64, operations: &[i64], result: i64) -> bool {
+    result > expected_result && result >= 0 && operations.iter().all(|&operation| operation >= 1)
+}
+
+/// `left` followed by the digits of `right`, e.g. 12 || 345 = 12345, or
+/// `None` on overflow or when `right` is negative and so has no such digits.
+fn concatenate(left: i64, right: i64) -> Option<i64> {
+    if right < 0 {
+        return None;
+    }
+    let mut shift = 10i64;
+    while shift <= right {
+        shift = shift.checked_mul(10)?;
+    }
+    left.checked_mul(shift)?.checked_add(right)
+}
+
+/// Sums the test values of every equation for which `find_operations` finds
+/// at least one valid combination of operators.
+fn total_calibration_result(equations: &[Equation], find_operations: fn(i64, Vec<i64>, i64) -> i64) -> i64 {
+    let progress = Progress::new("day 7 equations", equations.len());
+    let calibration_results = par_map(equations, |equation| {
+        let _span = tracing::debug_span!("equation", test_value = equation.test_value).entered();
+        let (&first, rest) = equation.operands.split_first().expect("an equation has at least one operand");
+        let number_of_valid_operations = find_operations(equation.test_value, rest.to_vec(), first);
+
+        progress.inc();
+
+        if number_of_valid_operations > 0 {
+            equation.test_value
+        } else {
+            0
+        }
+    });
+    progress.finish();
+
+    calibration_results.into_iter().sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::{solve_within, Part};
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+    use std::time::Duration;
+
+    const EXAMPLE: &str = "190: 10 19
+3267: 81 40 27
+83: 17 5
+156: 15 6
+7290: 6 8 6 15
+161011: 16 10 13
+192: 17 8 14
+21037: 9 7 18 13
+292: 11 6 16 20";
+
+    #[test]
+    fn test_example_part1() {
+        assert_eq!(Day7::part_one(&Day7::parse(EXAMPLE).unwrap()), Answer::from(3749i64));
+    }
+
+    #[test]
+    fn test_example_part2() {
+        assert_eq!(Day7::part_two(&Day7::parse(EXAMPLE).unwrap()), Answer::from(11387i64));
+    }
+
+    #[test]
+    fn test_empty_input() {
+        let equations = Day7::parse("").unwrap();
+        assert_eq!(Day7::part_one(&equations), Answer::from(0i64));
+        assert_eq!(Day7::part_two(&equations), Answer::from(0i64));
+    }
+
+    #[test]
+    fn test_single_line() {
+        let equations = Day7::parse("190: 10 19\n").unwrap();
+        assert_eq!(Day7::part_one(&equations), Answer::from(190i64));
+    }
+
+    #[test]
+    fn test_first_operand_is_never_dropped() {
+        // 0 * 10 + 19 would make 19, but every operator sits between operands
+        let equations = Day7::parse("19: 10 19\n").unwrap();
+        assert_eq!(Day7::part_one(&equations), Answer::from(0i64));
+        assert_eq!(Day7::part_two(&equations), Answer::from(0i64));
+    }
+
+    #[test]
+    fn test_overflowing_branches_are_dropped() {
+        let equations = Day7::parse("1: 9999999999 9999999999\n").unwrap();
+        assert_eq!(Day7::part_one(&equations), Answer::from(0i64));
+        assert_eq!(Day7::part_two(&equations), Answer::from(0i64));
+        assert_eq!(concatenate(12, 345), Some(12345));
+        assert_eq!(concatenate(5, 0), Some(50));
+        assert_eq!(concatenate(i64::MAX / 10, 10), None);
+    }
+
+    #[test]
+    fn test_negative_operands_are_not_concatenated() {
+        // 12 || -3 has no digits to append; it used to come out as 117
+        assert_eq!(concatenate(12, -3), None);
+        let equations = Day7::parse("117: 12 -3\n").unwrap();
+        assert_eq!(Day7::part_two(&equations), Answer::from(0i64));
+    }
+
+    #[test]
+    fn test_multiplying_by_zero_is_not_pruned() {
+        // 10 + 3 passes 5, but * 0 + 5 comes back to it
+        let equations = Day7::parse("5: 10 3 0 5\n").unwrap();
+        assert_eq!(Day7::part_one(&equations), Answer::from(5i64));
+    }
+
+    #[test]
+    fn test_missing_colon_is_rejected() {
+        let err = Day7::parse("190: 10 19\n83 17 5\n").unwrap_err();
+        assert_eq!(err.line, 2);
+    }
+
+    #[test]
+    fn test_long_equation_times_out() {
+        let line = format!("1:{}\n", " 0".repeat(30));
+        let parsed = aoc_core::Solver::parse(&Day7, &line).unwrap();
+        let answer = solve_within(&Day7, &parsed, Part::Two, Some(Duration::from_millis(50)));
+        assert_eq!(answer, None);
+    }
+
+    fn equations() -> impl Strategy<Value = Vec<Equation>> {
+        let equation = (1..5000i64, vec(1..30i64, 1..6))
+            .prop_map(|(test_value, operands)| Equation { test_value, operands });
+        vec(equation, 0..20)
+    }
+
+    proptest! {
+        #[test]
+        fn prop_concatenation_only_adds_solutions(equations in equations()) {
+            prop_assert!(part_two(&equations) >= part_one(&equations));
+        }
+
+        #[test]
+        fn prop_total_never_exceeds_all_test_values(equations in equations()) {
+            let all: i64 = equations.iter().map(|e| e.test_value).sum();
+            prop_assert!(part_two(&equations) <= all);
+        }
+
+        #[test]
+        fn prop_sum_and_product_are_always_valid(operands in vec(1..30i64, 1..6)) {
+            let sum = Equation { test_value: operands.iter().sum(), operands: operands.clone() };
+            let product = Equation { test_value: operands.iter().product(), operands };
+            let expected = sum.test_value + product.test_value;
+            prop_assert_eq!(part_one(&[sum, product]), expected);
+        }
+    }
+}
@@ -0,0 +1,13 @@
+#![no_main]
+
+use aoc_core::Solution;
+use libfuzzer_sys::fuzz_target;
+
+// Parsing must never panic: bad input is reported as a ParseError, which
+// must render without panicking too. The targets for the other days that
+// have a parser do the same.
+fuzz_target!(|input: &str| {
+    if let Err(err) = day_1::Day1::parse(input) {
+        let _ = err.render();
+    }
+});
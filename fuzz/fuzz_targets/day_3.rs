@@ -0,0 +1,10 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+// Day 3 keeps the raw memory dump and scans for instructions while solving,
+// so the scanners are what gets fuzzed here.
+fuzz_target!(|input: &str| {
+    let _ = day_3::part_one(input);
+    let _ = day_3::part_two(input);
+});
@@ -0,0 +1,10 @@
+#![no_main]
+
+use aoc_core::Solution;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    if let Err(err) = day_7::Day7::parse(input) {
+        let _ = err.render();
+    }
+});